@@ -0,0 +1,9 @@
+#!/bin/sh
+# Copies index.html to 404.html in the Trunk staging directory. GitHub Pages
+# serves 404.html for any path without a file, which is how routes such as
+# /releases and /release/:tag load when opened directly or refreshed. Run by
+# Trunk as a post_build hook (see Trunk.toml)
+set -eu
+
+dir="${TRUNK_STAGING_DIR:-dist}"
+cp "$dir/index.html" "$dir/404.html"
@@ -0,0 +1,27 @@
+#!/bin/sh
+# Writes sitemap.xml into the Trunk staging directory, listing every route in
+# src/routes/mod.rs except parameterised ones and those marked
+# "// not in sitemap". Run by Trunk as a post_build hook (see Trunk.toml)
+set -eu
+
+domain="${PANDORA_CANONICAL_DOMAIN:-pandora.moulberry.com}"
+out="${TRUNK_STAGING_DIR:-dist}/sitemap.xml"
+lastmod="$(git log -1 --format=%cs 2>/dev/null || date -u +%Y-%m-%d)"
+
+{
+    echo '<?xml version="1.0" encoding="UTF-8"?>'
+    echo '<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">'
+    awk '
+        /^[[:space:]]*\/\/ not in sitemap/ { skip = 1; next }
+        /#\[at\("/ {
+            path = $0
+            sub(/.*#\[at\("/, "", path)
+            sub(/"\)\].*/, "", path)
+            if (!skip && path !~ /:/) print path
+            skip = 0
+        }
+    ' src/routes/mod.rs | while read -r path; do
+        echo "    <url><loc>https://${domain}${path}</loc><lastmod>${lastmod}</lastmod></url>"
+    done
+    echo '</urlset>'
+} > "$out"
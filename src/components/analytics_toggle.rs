@@ -0,0 +1,34 @@
+use yew::prelude::*;
+use yew_hooks::use_local_storage;
+
+use crate::config::FeatureFlags;
+use crate::services::telemetry::{browser_opted_out, reporting_configured, OPT_OUT_KEY};
+
+/// Lets visitors opt out of the anonymous reporting. Hidden when reporting isn't set up
+#[function_component(AnalyticsToggle)]
+pub fn analytics_toggle() -> Html {
+    let opt_out = use_local_storage::<bool>(OPT_OUT_KEY.to_string());
+    let feature_flags = use_context::<FeatureFlags>().unwrap_or_else(FeatureFlags::from_env);
+
+    if !feature_flags.analytics || !reporting_configured() {
+        return Default::default();
+    }
+
+    if browser_opted_out() {
+        return html! {
+            <p class="is-size-7 has-text-centered">
+                {"Anonymous usage reporting is off because your browser asks not to be tracked"}
+            </p>
+        };
+    }
+
+    let enabled = !opt_out.unwrap_or(false);
+    let onchange = Callback::from(move |_| opt_out.set(enabled));
+
+    html! {
+        <label class="checkbox is-size-7 has-text-centered is-block">
+            <input class="mr-1" type="checkbox" checked={enabled} {onchange}/>
+            {"Send anonymous usage data (page views and detected vs chosen OS only)"}
+        </label>
+    }
+}
@@ -0,0 +1,36 @@
+use yew::prelude::*;
+
+use crate::preferences::use_auto_refresh_disabled;
+
+#[derive(Properties, PartialEq)]
+pub struct AutoRefreshToggleParams {
+    pub onrefresh: Callback<()>,
+    #[prop_or_default]
+    pub loading: bool,
+}
+
+/// Lets visitors turn off background re-fetching, with a button to refresh by hand
+#[function_component(AutoRefreshToggle)]
+pub fn auto_refresh_toggle(params: &AutoRefreshToggleParams) -> Html {
+    let disabled = use_auto_refresh_disabled();
+
+    let enabled = !disabled.unwrap_or(false);
+    let onchange = Callback::from(move |_| disabled.set(enabled));
+    let onclick = params.onrefresh.reform(|_: MouseEvent| ());
+
+    html! {
+        <div class="level is-mobile is-size-7 auto-refresh-toggle">
+            <div class="level-item">
+                <label class="checkbox">
+                    <input class="mr-1" type="checkbox" checked={enabled} {onchange}/>
+                    {"Refresh release info automatically"}
+                </label>
+            </div>
+            <div class="level-item">
+                <button class={classes!("button", "is-small", "is-dark", params.loading.then_some("is-loading"))} {onclick}>
+                    {"Refresh now"}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,130 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::config::FeatureFlags;
+use crate::components::downloads::DownloadLink;
+use crate::downloads::{downloads_by_type, recommended_download};
+use crate::platform::OperatingSystem;
+use crate::preferences::PackagePreference;
+use crate::routes::AppRoute;
+use crate::services::github::{latest_channels, GitHubReleases};
+
+#[derive(Properties, PartialEq)]
+pub struct ChannelCardsParams {
+    /// The cached release list. The cards never fetch it themselves and stay
+    /// hidden until the version history or home's idle prefetch has cached it
+    pub releases: Option<Rc<Vec<GitHubReleases>>>,
+    pub operating_system: OperatingSystem,
+    pub preference: PackagePreference,
+}
+
+/// Side-by-side summary of the newest stable release and the newest pre-release
+#[function_component(ChannelCards)]
+pub fn channel_cards(params: &ChannelCardsParams) -> Html {
+    let feature_flags = use_context::<FeatureFlags>().unwrap_or_else(FeatureFlags::from_env);
+
+    let Some(releases) = &params.releases else {
+        return Default::default();
+    };
+
+    let (stable, beta) = latest_channels(releases);
+    let beta = beta.filter(|_| feature_flags.beta_channel);
+    if stable.is_none() && beta.is_none() {
+        return Default::default();
+    }
+    let card = |title: &'static str, release: &GitHubReleases| {
+        html! {
+            <div class="column is-one-third">
+                <ChannelCard title={title} release={Rc::new(release.clone())} operating_system={params.operating_system} preference={params.preference}/>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="columns is-centered">
+            { stable.map(|release| card("Latest stable", release)).unwrap_or_default() }
+            { beta.map(|release| card("Latest beta", release)).unwrap_or_default() }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ChannelCardParams {
+    title: &'static str,
+    release: Rc<GitHubReleases>,
+    operating_system: OperatingSystem,
+    preference: PackagePreference,
+}
+
+#[function_component(ChannelCard)]
+fn channel_card(params: &ChannelCardParams) -> Html {
+    let downloads = downloads_by_type(&params.release);
+    let tag = params.release.tag_name.to_string();
+
+    let download = match recommended_download(params.operating_system, params.preference) {
+        Some(download_type) => html! {
+            <DownloadLink name={download_type.recommendation_label()} download={downloads.get(&download_type).cloned()} tooltip={download_type.tradeoff()}/>
+        },
+        None => html! {
+            <Link<AppRoute> to={AppRoute::release(&tag)}>
+                <ybc::Button classes="is-fullwidth is-link">{"View downloads"}</ybc::Button>
+            </Link<AppRoute>>
+        },
+    };
+
+    html! {
+        <div class="notification is-primary">
+            <p class="heading">{params.title}</p>
+            <ybc::Subtitle size={ybc::HeaderSize::Is4} classes="has-text-white">
+                <Link<AppRoute> to={AppRoute::release(&tag)}>{params.release.display_name()}</Link<AppRoute>>
+                <CopyButton text={tag.clone()} classes="ml-2 is-dark copy-version"/>
+            </ybc::Subtitle>
+            if params.release.has_title() {
+                <p class="is-size-7 mb-3"><code>{&tag}</code></p>
+            }
+            {download}
+        </div>
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::services::release_source::StubSource;
+    use crate::fixtures::release_with;
+    use crate::testing::render;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn cards(releases: Option<Vec<GitHubReleases>>) -> Html {
+        let releases = releases.map(Rc::new);
+        html! { <ChannelCards {releases} operating_system={OperatingSystem::Windows} preference={PackagePreference::Installer}/> }
+    }
+
+    #[wasm_bindgen_test]
+    async fn a_stable_release_gets_a_card() {
+        let releases = vec![release_with("1.2.3", &["Pandora-1.2.3-setup.exe"])];
+        let page = render(StubSource::empty().into_handle(), cards(Some(releases)));
+        assert!(page.wait_for(".columns").await.is_some(), "no channel cards rendered");
+        assert!(page.text().contains("Latest stable"), "{}", page.text());
+    }
+
+    #[wasm_bindgen_test]
+    async fn an_empty_release_list_renders_nothing() {
+        let page = render(StubSource::empty().into_handle(), cards(Some(Vec::new())));
+        page.settle().await;
+        assert_eq!(page.root.child_element_count(), 0, "{}", page.root.inner_html());
+    }
+
+    #[wasm_bindgen_test]
+    async fn an_uncached_release_list_renders_nothing() {
+        let page = render(StubSource::empty().into_handle(), cards(None));
+        page.settle().await;
+        assert_eq!(page.root.child_element_count(), 0, "{}", page.root.inner_html());
+    }
+}
@@ -0,0 +1,48 @@
+use yew::prelude::*;
+use yew_hooks::{use_clipboard, use_timeout};
+
+#[derive(Properties, PartialEq)]
+pub struct CopyButtonParams {
+    /// Text written to the clipboard
+    pub text: AttrValue,
+    #[prop_or(AttrValue::Static("Copy"))]
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub classes: Classes,
+    /// Hover text, "Copy <text>" by default
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+    /// Called after each copy, e.g. to show a toast
+    #[prop_or_default]
+    pub oncopy: Callback<()>,
+}
+
+/// Small button that copies `text` and briefly confirms with "Copied!"
+#[function_component(CopyButton)]
+pub fn copy_button(params: &CopyButtonParams) -> Html {
+    let clipboard = use_clipboard();
+    let copied = use_state(|| false);
+
+    let reset = {
+        let copied = copied.clone();
+        use_timeout(move || copied.set(false), 2000)
+    };
+
+    let onclick = {
+        let text = params.text.clone();
+        let copied = copied.clone();
+        let oncopy = params.oncopy.clone();
+        Callback::from(move |_| {
+            clipboard.write_text(text.to_string());
+            copied.set(true);
+            reset.reset();
+            oncopy.emit(());
+        })
+    };
+
+    html! {
+        <button class={classes!("button", "is-small", params.classes.clone())} title={params.title.clone().unwrap_or_else(|| format!("Copy {}", params.text).into())} {onclick}>
+            { if *copied { AttrValue::Static("Copied!") } else { params.label.clone() } }
+        </button>
+    }
+}
@@ -0,0 +1,80 @@
+use std::rc::Rc;
+
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+use crate::debug::log_tile_contrast;
+use crate::downloads::{classify_asset, DownloadType, DownloadsByType};
+use crate::platform::{detect_architecture, OperatingSystem};
+use crate::services::github::GitHubReleases;
+
+#[derive(Properties, PartialEq)]
+pub struct DebugPanelParams {
+    pub release: Option<GitHubReleases>,
+    pub downloads: Rc<DownloadsByType>,
+    pub detected_os: OperatingSystem,
+}
+
+/// Diagnostics for reproducing "missing download" reports, only shown with `?debug=1`
+#[function_component(DebugPanel)]
+pub fn debug_panel(params: &DebugPanelParams) -> Html {
+    use_effect_with_deps(|_| log_tile_contrast(), ());
+
+    let architecture = use_state(|| None);
+    {
+        let architecture = architecture.clone();
+        use_effect_with_deps(move |_| {
+            spawn_local(async move { architecture.set(Some(detect_architecture().await)) });
+        }, ());
+    }
+    let architecture = match &*architecture {
+        None => "Checking…".to_string(),
+        Some(Some(detected)) => format!("{} ({}-bit)", detected.architecture, detected.bitness),
+        Some(None) => "Unknown (no client hints)".to_string(),
+    };
+
+    let release_json = match &params.release {
+        Some(release) => serde_json::to_string_pretty(release).unwrap_or_else(|error| error.to_string()),
+        None => "No release data".to_string(),
+    };
+
+    let unclassified: Vec<_> = params.release.iter()
+        .flat_map(|release| release.assets.iter())
+        .filter(|asset| classify_asset(&asset.name).is_none())
+        .map(|asset| asset.name.clone())
+        .collect();
+
+    let mut classified: Vec<_> = params.downloads.iter().collect();
+    classified.sort_by_key(|(download_type, _)| format!("{:?}", download_type));
+
+    html! {
+        <ybc::Box classes="debug-panel">
+            <ybc::Subtitle size={ybc::HeaderSize::Is4} classes="has-text-white">{"Debug"}</ybc::Subtitle>
+            <p>{format!("Detected OS: {:?}", params.detected_os)}</p>
+            <p>{format!("Architecture: {}", architecture)}</p>
+            <div class="tags">
+                { for DownloadType::ALL.iter().copied().map(|download_type| {
+                    let found = params.downloads.contains_key(&download_type);
+                    html! {
+                        <span class={classes!("tag", if found { "is-success" } else { "is-danger" })} title={if found { "Asset found" } else { "No asset classified as this" }}>
+                            {format!("{} {:?}", if found { "✓" } else { "✗" }, download_type)}
+                        </span>
+                    }
+                }) }
+            </div>
+            if !unclassified.is_empty() {
+                <p>{"Unclassified assets:"}</p>
+                <ul>
+                    { for unclassified.iter().map(|name| html! { <li class="asset-name" title={name.to_string()}>{&**name}</li> }) }
+                </ul>
+            }
+            <p>{"Classified downloads:"}</p>
+            <ul>
+                { for classified.into_iter().map(|(download_type, download)| html! {
+                    <li class="asset-name" title={download.name.to_string()}><code>{format!("{:?}", download_type)}</code>{" → "}{&*download.name}</li>
+                }) }
+            </ul>
+            <pre>{release_json}</pre>
+        </ybc::Box>
+    }
+}
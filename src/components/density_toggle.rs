@@ -0,0 +1,38 @@
+use yew::prelude::*;
+
+use crate::preferences::{use_density, Density};
+use crate::theme::apply_density;
+
+/// Switches between comfortable and compact spacing, remembered across visits
+#[function_component(DensityToggle)]
+pub fn density_toggle() -> Html {
+    let stored = use_density();
+    let density = stored.unwrap_or_default();
+
+    use_effect_with_deps(|density| apply_density(*density), density);
+
+    let button = |label: &'static str, option: Density| {
+        let stored = stored.clone();
+        let selected = density == option;
+        html! {
+            <button
+                class={classes!("button", "is-small", if selected { "is-link" } else { "is-dark" })}
+                aria-pressed={selected.to_string()}
+                onclick={Callback::from(move |_| stored.set(option))}>
+                {label}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="level is-mobile is-size-7">
+            <div class="level-item">
+                <span class="mr-2">{"Spacing:"}</span>
+                <div class="buttons has-addons mb-0">
+                    {button("Comfortable", Density::Comfortable)}
+                    {button("Compact", Density::Compact)}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,393 @@
+use std::rc::Rc;
+
+use gloo::timers::callback::Timeout;
+use ybc::TileCtx::{Child, Parent};
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+use yew_router::prelude::Link;
+
+use crate::config;
+use crate::downloads::{collapsed_format, is_github_host, mirror_url, platforms_for, resume_hint, url_host, Download, DownloadType, DownloadsByType, PlatformDownloads, PACKAGE_COMPARISON};
+use crate::platform::OperatingSystem;
+use crate::preferences::{use_advanced_downloads_open, MirrorPreference, PackagePreference, RecentDownloads};
+use crate::routes::AppRoute;
+use crate::services::github::RELEASES_PAGE;
+use crate::shortcuts::PRIMARY_DOWNLOAD_ID;
+use crate::util::{format_bytes, format_date, format_relative};
+
+#[derive(Properties, PartialEq)]
+pub struct DownloadLinkParams {
+    pub name: String,
+    pub download: Option<Download>,
+    #[prop_or(true)]
+    pub highlighted: bool,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub tooltip: Option<AttrValue>,
+    /// Show the file size as reported by the API, and a resume hint for large
+    /// files served by GitHub
+    #[prop_or_default]
+    pub show_size: bool,
+}
+
+/// How long after a download click the page can keep focus before the
+/// "didn't start?" hint appears
+const STUCK_DOWNLOAD_MILLIS: u32 = 5000;
+
+/// Shows a hint when the visitor is still looking at the page a few seconds after
+/// clicking a download, which usually means the browser blocked or dropped it.
+/// Returns whether to show the hint and the click handler that arms it
+#[hook]
+fn use_stuck_download_hint() -> (bool, Callback<MouseEvent>) {
+    let stuck = use_state(|| false);
+    let timer = use_mut_ref(|| None::<Timeout>);
+
+    {
+        let timer = timer.clone();
+        use_event_with_window("blur", move |_: Event| {
+            timer.borrow_mut().take();
+        });
+    }
+    {
+        let timer = timer.clone();
+        use_event_with_window("visibilitychange", move |_: Event| {
+            let hidden = web_sys::window()
+                .and_then(|window| window.document())
+                .is_some_and(|document| document.hidden());
+            if hidden {
+                timer.borrow_mut().take();
+            }
+        });
+    }
+
+    let onclick = {
+        let stuck = stuck.clone();
+        Callback::from(move |_: MouseEvent| {
+            stuck.set(false);
+            let stuck = stuck.clone();
+            *timer.borrow_mut() = Some(Timeout::new(STUCK_DOWNLOAD_MILLIS, move || stuck.set(true)));
+        })
+    };
+
+    (*stuck, onclick)
+}
+
+#[function_component(DownloadLink)]
+pub fn download_link(params: &DownloadLinkParams) -> Html {
+    let (stuck, on_download_click) = use_stuck_download_hint();
+    let recent_downloads = use_context::<RecentDownloads>();
+    let mirror = use_context::<MirrorPreference>();
+    let mirror = mirror.as_ref().and_then(MirrorPreference::base);
+
+    let classes = if params.highlighted {
+        classes!("is-fullwidth", "is-link")
+    } else {
+        classes!("is-fullwidth", "is-link", "is-outlined")
+    };
+    let tooltip_classes = params.tooltip.is_some().then_some("is-block has-tooltip-multiline has-tooltip-arrow");
+
+    if let Some(download) = &params.download {
+        let java_badge = match download.bundles_java {
+            Some(true) => html! { <span class="tag is-success ml-2">{"Java included"}</span> },
+            Some(false) => html! { <span class="tag is-warning ml-2">{"Bring your own Java"}</span> },
+            None => Default::default(),
+        };
+        let size = if params.show_size {
+            html! { <span class="ml-2 is-size-7">{format!("({})", format_bytes(download.size))}</span> }
+        } else {
+            Default::default()
+        };
+        let href = mirror_url(&download.url, mirror);
+        let resume_tag = if params.show_size && resume_hint(download.size, &href) {
+            html! {
+                <span class="tag is-dark ml-2" title="If the download is interrupted, your browser can pick it up where it left off">{"Resumable"}</span>
+            }
+        } else {
+            Default::default()
+        };
+
+        let onclick = {
+            let name = download.name.clone();
+            Callback::from(move |event: MouseEvent| {
+                if let Some(recent) = &recent_downloads {
+                    recent.record(&name);
+                }
+                on_download_click.emit(event);
+            })
+        };
+        // Where the file really comes from, so a mirror the visitor picked is never mistaken for GitHub
+        let source = match url_host(&href) {
+            Some((host, secure)) => {
+                let official = is_github_host(host);
+                let lock = if secure { "🔒 " } else { "" };
+                html! {
+                    <span class={classes!("download-source", (!official).then_some("is-mirror"))} title={if official { "Served by GitHub" } else { "Served by a mirror you selected" }}>
+                        {format!("{}from {}", lock, host)}
+                        if !official {
+                            <span class="tag is-warning is-light ml-1">{"mirror"}</span>
+                        }
+                    </span>
+                }
+            },
+            None => Default::default(),
+        };
+
+        html! {
+            <>
+            <a id={params.id.clone()} class={tooltip_classes} data-tooltip={params.tooltip.clone()} download="true" href={href} {onclick}>
+                <ybc::Button {classes}>
+                    {&params.name}
+                    {size}
+                    {java_badge}
+                    {resume_tag}
+                </ybc::Button>
+            </a>
+            <code class="download-filename" title={download.name.to_string()}>{&download.name}</code>
+            {source}
+            if stuck {
+                <p class="is-size-7 has-text-centered mt-1" role="status">
+                    {"Still here? If the download didn't start, "}
+                    <a href={RELEASES_PAGE} target="_blank" rel="noopener noreferrer">{"try the GitHub link"}</a>
+                </p>
+            }
+            </>
+        }
+    } else {
+        html! {
+            <div id={params.id.clone()} class={tooltip_classes} data-tooltip={params.tooltip.clone()}>
+                <ybc::Button {classes}>
+                    {&params.name}
+                </ybc::Button>
+            </div>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DownloadTilesParams {
+    pub downloads: Rc<DownloadsByType>,
+    #[prop_or_default]
+    pub preference: PackagePreference,
+    /// Download that the jump-to-downloads shortcut should focus
+    #[prop_or_default]
+    pub primary: Option<DownloadType>,
+    /// Platform whose tile goes first
+    #[prop_or(OperatingSystem::Unknown)]
+    pub operating_system: OperatingSystem,
+    /// Prefer the ARM64 `.deb` over the x64 one, leaving the x64 build under advanced
+    #[prop_or_default]
+    pub arm64: bool,
+}
+
+/// Each platform shows only its preferred build until the visitor opens
+/// "Advanced downloads", which reveals the portable and alternative formats
+#[function_component(DownloadTiles)]
+pub fn download_tiles(params: &DownloadTilesParams) -> Html {
+    let advanced_open = use_advanced_downloads_open();
+    let show_advanced = advanced_open.unwrap_or(false);
+    let on_advanced_toggle = {
+        let advanced_open = advanced_open.clone();
+        Callback::from(move |_| advanced_open.set(!show_advanced))
+    };
+    let visible = |platform: &PlatformDownloads, download_type: DownloadType| {
+        let collapsed = collapsed_format(platform, params.preference, params.arm64, &params.downloads);
+        show_advanced || Some(download_type) == collapsed || Some(download_type) == params.primary
+    };
+
+    let button = |label: &'static str, download_type: DownloadType| {
+        let highlighted = download_type.is_portable() == (params.preference == PackagePreference::Portable);
+        let id = (params.primary == Some(download_type)).then_some(AttrValue::Static(PRIMARY_DOWNLOAD_ID));
+
+        html! {
+            <DownloadLink
+                name={label}
+                download={params.downloads.get(&download_type).cloned()}
+                {highlighted}
+                {id}
+                tooltip={download_type.tradeoff()}/>
+        }
+    };
+
+    let universal = params.downloads.get(&DownloadType::Universal).map(|download| html! {
+        <ybc::Tile ctx={Parent}>
+            <ybc::Tile ctx={Child} classes="notification is-primary">
+                <ybc::Subtitle size={ybc::HeaderSize::Is3} classes="has-text-white">
+                    {"Works everywhere"}
+                </ybc::Subtitle>
+                <div class="download-buttons">
+                    <DownloadLink name="All platforms" download={download.clone()} tooltip={DownloadType::Universal.tradeoff()}/>
+                </div>
+            </ybc::Tile>
+        </ybc::Tile>
+    });
+
+    html! {
+        <>
+        if let Some(universal) = universal {
+            <ybc::Tile>{universal}</ybc::Tile>
+        }
+        <ybc::Tile classes="download-tiles">
+            { for platforms_for(params.operating_system).into_iter().map(|platform| html! {
+                <ybc::Tile ctx={Parent} size={ybc::TileSize::Four}>
+                    <ybc::Tile ctx={Child} classes="notification is-primary">
+                        <ybc::Subtitle size={ybc::HeaderSize::Is3} classes="has-text-white">
+                            {platform.title}
+                        </ybc::Subtitle>
+                        // A loaded release with nothing for this platform, e.g. because its CI build failed
+                        if !params.downloads.is_empty() && platform.formats.iter().all(|(_, download_type)| !params.downloads.contains_key(download_type)) {
+                            <p>
+                                {"No build available for this platform in this release. "}
+                                <Link<AppRoute> to={AppRoute::Versions}>{"Try an older version"}</Link<AppRoute>>
+                            </p>
+                        } else {
+                            <div class="download-buttons">
+                                { for platform.formats.iter()
+                                    .filter(|&&(_, download_type)| visible(platform, download_type))
+                                    .map(|&(label, download_type)| button(label, download_type)) }
+                            </div>
+                        }
+                        {{
+                            let updated_at = platform.formats.iter()
+                                .filter_map(|(_, download_type)| params.downloads.get(download_type)?.updated_at)
+                                .max();
+                            updated_at.map(|date| html! {
+                                <p class="is-size-7 mt-2" title={format_date(date)}>
+                                    {format!("Updated {}", format_relative(date))}
+                                </p>
+                            }).unwrap_or_default()
+                        }}
+                    </ybc::Tile>
+                </ybc::Tile>
+            }) }
+        </ybc::Tile>
+        <div class="buttons is-centered">
+            <button class="button is-small is-dark" aria-expanded={show_advanced.to_string()} onclick={on_advanced_toggle}>
+                { if show_advanced { "Hide advanced downloads" } else { "Advanced downloads (portable, AppImage)" } }
+            </button>
+        </div>
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PackagePreferenceToggleParams {
+    pub preference: PackagePreference,
+    pub onchange: Callback<PackagePreference>,
+}
+
+#[function_component(PackagePreferenceToggle)]
+pub fn package_preference_toggle(params: &PackagePreferenceToggleParams) -> Html {
+    let button = |label: &'static str, preference: PackagePreference| {
+        let selected = params.preference == preference;
+        let onclick = params.onchange.reform(move |_| preference);
+        html! {
+            <button class={classes!("button", "is-small", selected.then_some("is-link"))} aria-pressed={selected.to_string()} {onclick}>
+                {label}
+            </button>
+        }
+    };
+
+    html! {
+        <div class="buttons has-addons is-centered">
+            {button("Prefer installers", PackagePreference::Installer)}
+            {button("Prefer portable", PackagePreference::Portable)}
+        </div>
+    }
+}
+
+/// Expandable "Which should I choose?" table comparing installers and portable builds
+#[function_component(PackageComparisonPanel)]
+pub fn package_comparison_panel() -> Html {
+    let mark = |supported: bool| {
+        if supported {
+            html! { <td class="has-text-centered has-text-success" aria-label="Yes">{"✓"}</td> }
+        } else {
+            html! { <td class="has-text-centered has-text-grey" aria-label="No">{"✗"}</td> }
+        }
+    };
+
+    html! {
+        <details class="package-comparison">
+            <summary>{"Which should I choose?"}</summary>
+            <table class="table is-narrow">
+                <thead>
+                    <tr>
+                        <th></th>
+                        <th class="has-text-centered">{"Installer"}</th>
+                        <th class="has-text-centered">{"Portable"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    { for PACKAGE_COMPARISON.iter().map(|row| html! {
+                        <tr>
+                            <th>{row.feature}</th>
+                            {mark(row.installer)}
+                            {mark(row.portable)}
+                        </tr>
+                    }) }
+                </tbody>
+            </table>
+        </details>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PlatformSelectParams {
+    pub selected: OperatingSystem,
+    pub onchange: Callback<OperatingSystem>,
+}
+
+#[function_component(PlatformSelect)]
+pub fn platform_select(params: &PlatformSelectParams) -> Html {
+    let onchange = params.onchange.reform(|event: Event| {
+        let select: web_sys::HtmlSelectElement = event.target_unchecked_into();
+        OperatingSystem::from_id(&select.value())
+    });
+
+    html! {
+        <div class="select is-small">
+            <select aria-label="Operating system" {onchange}>
+                if params.selected == OperatingSystem::Unknown {
+                    <option value={OperatingSystem::Unknown.id()} selected=true disabled=true>{"Choose your OS"}</option>
+                }
+                { for OperatingSystem::SELECTABLE.iter().copied().map(|os| html! {
+                    <option value={os.id()} selected={os == params.selected}>{os.name()}</option>
+                }) }
+            </select>
+        </div>
+    }
+}
+
+#[function_component(MirrorSelect)]
+pub fn mirror_select() -> Html {
+    let mirrors = config::download_mirrors();
+    let preference = use_context::<MirrorPreference>();
+    let Some(preference) = preference else {
+        return Default::default();
+    };
+    if mirrors.is_empty() {
+        return Default::default();
+    }
+
+    let selected = preference.base();
+    let onchange = Callback::from(move |event: Event| {
+        let select: web_sys::HtmlSelectElement = event.target_unchecked_into();
+        let value = select.value();
+        preference.set(Some(value.as_str()).filter(|value| !value.is_empty()));
+    });
+
+    html! {
+        <div class="level is-mobile">
+            <span class="level-item mr-2">{"Download mirror:"}</span>
+            <div class="select is-small">
+                <select aria-label="Download mirror" {onchange}>
+                    <option value="" selected={selected.is_none()}>{"GitHub (default)"}</option>
+                    { for mirrors.iter().map(|mirror| html! {
+                        <option value={mirror.base} selected={selected == Some(mirror.base)}>{mirror.name}</option>
+                    }) }
+                </select>
+            </div>
+        </div>
+    }
+}
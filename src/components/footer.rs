@@ -0,0 +1,37 @@
+use yew::prelude::*;
+
+use crate::components::system_info::SystemInfo;
+use crate::config;
+
+const WEBSITE_COMMITS: &str = "https://github.com/Moulberry/PandoraLauncherWebsite/commit";
+const GITHUB_OWNER: &str = "https://github.com/Moulberry";
+
+/// Site footer with the build this page was served from, for debugging reports
+#[function_component(SiteFooter)]
+pub fn site_footer() -> Html {
+    let commit = match config::GIT_HASH.filter(|hash| !hash.is_empty()) {
+        Some(hash) => html! {
+            <a href={format!("{}/{}", WEBSITE_COMMITS, hash)} target="_blank" rel="noopener noreferrer">{hash}</a>
+        },
+        None => html! { {"development"} },
+    };
+    let date = config::BUILD_DATE
+        .filter(|date| !date.is_empty())
+        .map(|date| format!(", built {}", date))
+        .unwrap_or_default();
+
+    html! {
+        <ybc::Footer classes="site-footer">
+            <p class="has-text-centered is-size-7 has-text-grey mb-1">
+                {"The official site is "}
+                <a href={format!("https://{}/", config::CANONICAL_DOMAIN)}>{config::CANONICAL_DOMAIN}</a>
+                {" and releases are only published by "}
+                <a href={GITHUB_OWNER} target="_blank" rel="noopener noreferrer">{"Moulberry on GitHub"}</a>
+            </p>
+            <p class="has-text-centered is-size-7 has-text-grey">
+                {"Site build "}{commit}{date}
+            </p>
+            <SystemInfo/>
+        </ybc::Footer>
+    }
+}
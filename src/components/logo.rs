@@ -0,0 +1,37 @@
+use yew::prelude::*;
+use yew_router::prelude::Link;
+
+use crate::routes::AppRoute;
+
+const LOGO_SVG: &str = include_str!("logo.svg");
+
+#[derive(Properties, PartialEq)]
+pub struct LogoParams {
+    #[prop_or_default]
+    pub classes: Classes,
+}
+
+/// The Pandora wordmark, inlined so it paints with the page and follows the
+/// surrounding text colour through `currentColor`
+#[function_component(Logo)]
+pub fn logo(params: &LogoParams) -> Html {
+    html! {
+        <span class={classes!("logo-container", params.classes.clone())}>
+            {Html::from_html_unchecked(AttrValue::Static(LOGO_SVG))}
+        </span>
+    }
+}
+
+/// Small logo linking back to the home page from the other routes. The wordmark
+/// itself is hidden from assistive technology; the link carries the name
+#[function_component(HomeLogoLink)]
+pub fn home_logo_link() -> Html {
+    html! {
+        <div class="has-text-centered mb-5">
+            <Link<AppRoute> to={AppRoute::Home} classes="logo-link">
+                <span class="is-sr-only">{"Pandora Launcher, home"}</span>
+                <span aria-hidden="true"><Logo classes="nav-logo"/></span>
+            </Link<AppRoute>>
+        </div>
+    }
+}
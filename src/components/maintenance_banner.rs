@@ -0,0 +1,30 @@
+use yew::prelude::*;
+use yew_hooks::use_session_storage;
+
+use crate::config;
+
+const DISMISSED_KEY: &str = "pandora.maintenance_dismissed";
+
+/// Site-wide notice configured through `PANDORA_MAINTENANCE_MESSAGE`. Dismissing
+/// it hides the message for the rest of the session
+#[function_component(MaintenanceBanner)]
+pub fn maintenance_banner() -> Html {
+    let dismissed = use_session_storage::<String>(DISMISSED_KEY.to_string());
+
+    let Some(message) = config::MAINTENANCE_MESSAGE.filter(|message| !message.trim().is_empty()) else {
+        return Default::default();
+    };
+    // Keyed on the message so a new notice shows even if an older one was dismissed
+    if dismissed.as_deref() == Some(message) {
+        return Default::default();
+    }
+
+    let ondismiss = Callback::from(move |_| dismissed.set(message.to_string()));
+
+    html! {
+        <ybc::Notification classes="is-warning is-radiusless mb-0 maintenance-banner">
+            <button class="delete" aria-label="Dismiss" onclick={ondismiss}></button>
+            {message}
+        </ybc::Notification>
+    }
+}
@@ -0,0 +1,21 @@
+pub mod analytics_toggle;
+pub mod auto_refresh_toggle;
+pub mod channels;
+pub mod copy_button;
+pub mod debug_panel;
+pub mod density_toggle;
+pub mod downloads;
+pub mod footer;
+pub mod logo;
+pub mod maintenance_banner;
+pub mod notify_button;
+pub mod open_in_launcher;
+pub mod recent_downloads;
+pub mod release_flash;
+pub mod release_notes;
+pub mod retry;
+pub mod share_button;
+pub mod shortcut_help;
+pub mod storage_notice;
+pub mod system_info;
+pub mod update_banner;
@@ -0,0 +1,68 @@
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+use crate::services::push::{permission_denied, push_configured, push_supported, subscribe};
+
+const WATCH_RELEASES_URL: &str = "https://github.com/Moulberry/PandoraLauncher";
+
+#[derive(Clone, PartialEq)]
+enum NotifyState {
+    Idle,
+    Subscribing,
+    Subscribed,
+    Failed(String),
+}
+
+/// Opt-in release notifications over Web Push. Falls back to pointing at
+/// GitHub's "Watch → Releases" when push isn't available or was refused
+#[function_component(NotifyButton)]
+pub fn notify_button() -> Html {
+    let state = use_state(|| NotifyState::Idle);
+
+    let fallback = |reason: Option<&str>| html! {
+        <p class="is-size-7 has-text-centered">
+            {reason.map(|reason| format!("{}. ", reason)).unwrap_or_default()}
+            {"To hear about new versions, "}
+            <a href={WATCH_RELEASES_URL} target="_blank" rel="noopener noreferrer">{"watch releases on GitHub"}</a>
+            {" (Watch → Custom → Releases)"}
+        </p>
+    };
+
+    if !push_configured() || !push_supported() {
+        return fallback(None);
+    }
+    if permission_denied() {
+        return fallback(Some("Notifications are blocked for this site"));
+    }
+
+    let onclick = {
+        let state = state.clone();
+        Callback::from(move |_| {
+            let state = state.clone();
+            state.set(NotifyState::Subscribing);
+            spawn_local(async move {
+                match subscribe().await {
+                    Ok(()) => state.set(NotifyState::Subscribed),
+                    Err(error) => {
+                        log::warn!("Failed to subscribe to release notifications: {}", error);
+                        state.set(NotifyState::Failed(error));
+                    },
+                }
+            });
+        })
+    };
+
+    match &*state {
+        NotifyState::Subscribed => html! {
+            <p class="is-size-7 has-text-centered">{"You'll be notified when a new version is released"}</p>
+        },
+        NotifyState::Failed(error) => fallback(Some(error)),
+        NotifyState::Idle | NotifyState::Subscribing => html! {
+            <div class="buttons is-centered">
+                <button class={classes!("button", "is-small", "is-dark", (*state == NotifyState::Subscribing).then_some("is-loading"))} {onclick}>
+                    {"Notify me about new versions"}
+                </button>
+            </div>
+        },
+    }
+}
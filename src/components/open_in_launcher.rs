@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+
+use crate::config::LAUNCHER_SCHEME;
+use crate::downloads::{mirror_url, Download};
+use crate::preferences::MirrorPreference;
+
+/// How long to wait for the launcher to take focus before assuming nothing
+/// handled the link
+const HANDOFF_TIMEOUT_MILLIS: u32 = 1500;
+
+#[derive(Properties, PartialEq)]
+pub struct OpenInLauncherParams {
+    pub tag: Arc<str>,
+    /// Started instead when no installed launcher picks up the link
+    pub fallback: Option<Download>,
+}
+
+/// "Open in Pandora" button for visitors who already have the launcher. The
+/// browser gives no answer on whether a custom scheme was handled, so the page
+/// losing focus shortly after counts as success and anything else starts the
+/// fallback download
+#[function_component(OpenInLauncher)]
+pub fn open_in_launcher(params: &OpenInLauncherParams) -> Html {
+    let mirror = use_context::<MirrorPreference>();
+    let left_page = use_mut_ref(|| false);
+    let pending = use_mut_ref(|| None::<Timeout>);
+
+    {
+        let left_page = left_page.clone();
+        use_event_with_window("blur", move |_: Event| *left_page.borrow_mut() = true);
+    }
+    {
+        let left_page = left_page.clone();
+        use_event_with_window("visibilitychange", move |_: Event| {
+            let hidden = web_sys::window()
+                .and_then(|window| window.document())
+                .is_some_and(|document| document.hidden());
+            if hidden {
+                *left_page.borrow_mut() = true;
+            }
+        });
+    }
+
+    let Some(scheme) = LAUNCHER_SCHEME else {
+        return Default::default();
+    };
+
+    let launcher_url = format!("{}://install?version={}", scheme, js_sys::encode_uri_component(&params.tag));
+    let fallback_url = params.fallback.as_ref()
+        .map(|download| mirror_url(&download.url, mirror.as_ref().and_then(MirrorPreference::base)));
+
+    let onclick = Callback::from(move |_| {
+        let Some(location) = web_sys::window().map(|window| window.location()) else {
+            return;
+        };
+        *left_page.borrow_mut() = false;
+        if let Err(error) = location.set_href(&launcher_url) {
+            log::warn!("Failed to open {}: {:?}", launcher_url, error);
+        }
+
+        let left_page = left_page.clone();
+        let fallback_url = fallback_url.clone();
+        *pending.borrow_mut() = Some(Timeout::new(HANDOFF_TIMEOUT_MILLIS, move || {
+            if *left_page.borrow() {
+                return;
+            }
+            log::info!("Nothing handled the launcher link, starting the download instead");
+            if let Some(fallback_url) = fallback_url {
+                let _ = location.set_href(&fallback_url);
+            }
+        }));
+    });
+
+    html! {
+        <div class="buttons is-centered mt-2">
+            <button class="button is-small is-dark" title="Already installed? Open this version in the launcher" {onclick}>
+                {"Open in Pandora"}
+            </button>
+        </div>
+    }
+}
@@ -0,0 +1,26 @@
+use yew::prelude::*;
+
+use crate::preferences::RecentDownloads;
+
+/// "You downloaded: …" reminder for visitors comparing several builds
+#[function_component(RecentDownloadsNote)]
+pub fn recent_downloads_note() -> Html {
+    let recent = use_context::<RecentDownloads>();
+    let Some(recent) = recent.filter(|recent| !recent.names().is_empty()) else {
+        return Default::default();
+    };
+
+    html! {
+        <p class="is-size-7 has-text-centered has-text-grey-light mb-3">
+            {"You downloaded this session: "}
+            { for recent.names().iter().enumerate().map(|(index, name)| html! {
+                <>
+                if index > 0 {
+                    {", "}
+                }
+                <code class="download-filename is-inline" title={name.clone()}>{name}</code>
+                </>
+            }) }
+        </p>
+    }
+}
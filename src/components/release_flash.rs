@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use yew::prelude::*;
+use yew_hooks::use_timeout;
+
+#[derive(Properties, PartialEq)]
+pub struct ReleaseFlashParams {
+    /// Tag of the release currently on screen
+    pub tag: Option<Arc<str>>,
+}
+
+/// Briefly announces "Updated to <tag>" when the displayed release changes
+/// while the page is open. Nothing is shown for the first release loaded
+#[function_component(ReleaseFlash)]
+pub fn release_flash(params: &ReleaseFlashParams) -> Html {
+    let shown = use_mut_ref(|| params.tag.clone());
+    let flash = use_state(|| None::<Arc<str>>);
+
+    let hide = {
+        let flash = flash.clone();
+        use_timeout(move || flash.set(None), 5000)
+    };
+
+    {
+        let flash = flash.clone();
+        use_effect_with_deps(move |tag| {
+            let previous = shown.replace(tag.clone());
+            if let (Some(previous), Some(tag)) = (previous, tag) {
+                if previous != *tag {
+                    log::info!("Release changed from {} to {}", previous, tag);
+                    flash.set(Some(tag.clone()));
+                    hide.reset();
+                }
+            }
+        }, params.tag.clone());
+    }
+
+    match &*flash {
+        Some(tag) => html! {
+            <div class="notification is-success" role="status">
+                {format!("Updated to {}", tag)}
+            </div>
+        },
+        None => Default::default(),
+    }
+}
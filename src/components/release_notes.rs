@@ -0,0 +1,103 @@
+use yew::prelude::*;
+
+const PREVIEW_MAX_LINES: usize = 20;
+const PREVIEW_MAX_CHARS: usize = 2000;
+
+/// Byte offset to cut `body` at for a preview, or `None` if it is short enough to
+/// show in full. Only cuts at blank lines outside fenced code blocks, so lists,
+/// paragraphs and code blocks are never split mid-element. The length limit
+/// counts characters rather than bytes, so CJK and emoji-heavy notes aren't
+/// collapsed three or four times sooner than Latin ones
+fn preview_cut(body: &str) -> Option<usize> {
+    if body.lines().count() <= PREVIEW_MAX_LINES && body.chars().count() <= PREVIEW_MAX_CHARS {
+        return None;
+    }
+
+    let mut in_fence = false;
+    let mut offset = 0;
+    let mut chars = 0;
+    let mut cut = None;
+    for (index, line) in body.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if trimmed.is_empty() && !in_fence && offset > 0 {
+            if index > PREVIEW_MAX_LINES || chars > PREVIEW_MAX_CHARS {
+                // Nothing fit within the limits, so fall back to the first boundary past them
+                return cut.or(Some(offset));
+            }
+            cut = Some(offset);
+        }
+        offset += line.len();
+        chars += line.chars().count();
+    }
+
+    cut.filter(|&cut| cut < body.trim_end().len())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReleaseNotesParams {
+    pub body: AttrValue,
+}
+
+/// Release notes, collapsed to a preview with a "Show more" toggle when long
+#[function_component(ReleaseNotes)]
+pub fn release_notes(params: &ReleaseNotesParams) -> Html {
+    let expanded = use_state(|| false);
+    let cut = preview_cut(&params.body);
+
+    let text = match cut {
+        Some(cut) if !*expanded => params.body[..cut].trim_end(),
+        _ => params.body.as_str(),
+    };
+
+    let toggle = cut.map(|_| {
+        let label = if *expanded { "Show less" } else { "Show more" };
+        let onclick = {
+            let expanded = expanded.clone();
+            Callback::from(move |_| expanded.set(!*expanded))
+        };
+        html! {
+            <button class="button is-small is-dark mt-2" aria-expanded={expanded.to_string()} {onclick}>{label}</button>
+        }
+    });
+
+    html! {
+        <div class="release-notes">
+            <p style="white-space: pre-wrap">{text}</p>
+            {toggle.unwrap_or_default()}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Paragraphs of emoji and CJK text, 201 characters but 576 bytes each
+    fn multibyte_notes(paragraphs: usize) -> String {
+        let paragraph = format!("{}\n", "🎉 新機能を追加".repeat(25));
+        vec![paragraph; paragraphs].join("\n")
+    }
+
+    #[test]
+    fn multibyte_notes_under_the_limit_are_shown_in_full() {
+        let body = multibyte_notes(6);
+        assert!(body.len() > PREVIEW_MAX_CHARS);
+        assert!(body.chars().count() <= PREVIEW_MAX_CHARS);
+        assert_eq!(preview_cut(&body), None);
+    }
+
+    #[test]
+    fn multibyte_notes_are_cut_by_characters_on_a_boundary() {
+        let body = multibyte_notes(10);
+        let cut = preview_cut(&body).expect("notes over the limit should be cut");
+        assert!(body.is_char_boundary(cut));
+
+        let kept = body[..cut].chars().count();
+        assert!(kept <= PREVIEW_MAX_CHARS, "kept {} characters", kept);
+        // Counting bytes would have stopped after three paragraphs
+        assert!(kept > 1500, "kept {} characters", kept);
+        assert!(body[..cut].ends_with("加\n"));
+    }
+}
@@ -0,0 +1,35 @@
+use yew::prelude::*;
+
+use crate::services::cache;
+
+#[derive(Properties, PartialEq)]
+pub struct RetryParams {
+    pub onretry: Callback<()>,
+    /// Cache entry cleared by "Force refresh". That button is hidden when unset
+    #[prop_or_default]
+    pub cache_key: Option<&'static str>,
+}
+
+/// Buttons for error states: a plain retry, and a force refresh that drops the
+/// cached response first in case it is what's broken
+#[function_component(Retry)]
+pub fn retry(params: &RetryParams) -> Html {
+    let onretry = params.onretry.reform(|_: MouseEvent| ());
+    let onforcerefresh = params.cache_key.map(|key| {
+        params.onretry.reform(move |_: MouseEvent| {
+            log::info!("Clearing {} before refetching", key);
+            cache::remove(key);
+        })
+    });
+
+    html! {
+        <div class="buttons is-centered mt-3">
+            <button class="button is-small is-link" onclick={onretry}>{"Try again"}</button>
+            if let Some(onforcerefresh) = onforcerefresh {
+                <button class="button is-small is-dark" title="Clears cached release data before retrying" onclick={onforcerefresh}>
+                    {"Force refresh"}
+                </button>
+            }
+        </div>
+    }
+}
@@ -0,0 +1,57 @@
+use js_sys::{Function, Object, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+
+/// `navigator.share`, which only mobile and a few desktop browsers have
+fn native_share() -> Option<Function> {
+    let navigator = web_sys::window()?.navigator();
+    Reflect::get(&navigator, &JsValue::from_str("share")).ok()?.dyn_into::<Function>().ok()
+}
+
+fn current_page() -> (String, String) {
+    let window = web_sys::window();
+    let url = window.as_ref().and_then(|window| window.location().href().ok()).unwrap_or_default();
+    let title = window.and_then(|window| window.document()).map(|document| document.title()).unwrap_or_default();
+    (url, title)
+}
+
+/// Opens the native share sheet. Dismissing the sheet rejects the promise,
+/// which isn't worth reporting
+async fn share(share: Function) {
+    let (url, title) = current_page();
+    let data = Object::new();
+    let _ = Reflect::set(&data, &JsValue::from_str("url"), &JsValue::from_str(&url));
+    let _ = Reflect::set(&data, &JsValue::from_str("title"), &JsValue::from_str(&title));
+
+    let navigator = web_sys::window().map(|window| JsValue::from(window.navigator())).unwrap_or_default();
+    let Some(promise) = share.call1(&navigator, &data).ok().and_then(|promise| promise.dyn_into::<Promise>().ok()) else {
+        return;
+    };
+    if let Err(error) = JsFuture::from(promise).await {
+        log::debug!("Share cancelled or failed: {:?}", error);
+    }
+}
+
+/// Shares the current page through the native share sheet where there is one,
+/// otherwise copies its link
+#[function_component(ShareButton)]
+pub fn share_button() -> Html {
+    match native_share() {
+        Some(native) => {
+            let onclick = Callback::from(move |_| {
+                let native = native.clone();
+                spawn_local(share(native));
+            });
+            html! {
+                <button class="button is-small is-dark" {onclick}>{"Share"}</button>
+            }
+        },
+        None => html! {
+            <CopyButton text={current_page().0} label="Copy link to share" title="Copy a link to this page" classes="is-dark"/>
+        },
+    }
+}
@@ -0,0 +1,40 @@
+use yew::prelude::*;
+
+use crate::focus_trap::use_focus_trap;
+use crate::shortcuts::shortcuts;
+
+#[derive(Properties, PartialEq)]
+pub struct ShortcutHelpParams {
+    pub open: bool,
+    pub onclose: Callback<()>,
+}
+
+#[function_component(ShortcutHelp)]
+pub fn shortcut_help(params: &ShortcutHelpParams) -> Html {
+    let trap = use_focus_trap(params.open, params.onclose.clone());
+    let onclose = params.onclose.reform(|_: MouseEvent| ());
+
+    html! {
+        <div ref={trap} class={classes!("modal", params.open.then_some("is-active"))} role="dialog" aria-modal="true" aria-labelledby="shortcut-help-title">
+            <div class="modal-background" onclick={onclose.clone()}></div>
+            <div class="modal-card">
+                <header class="modal-card-head">
+                    <p id="shortcut-help-title" class="modal-card-title">{"Keyboard shortcuts"}</p>
+                    <button class="delete" aria-label="Close" onclick={onclose}></button>
+                </header>
+                <section class="modal-card-body">
+                    <table class="table is-fullwidth shortcut-table">
+                        <tbody>
+                        { for shortcuts().into_iter().map(|shortcut| html! {
+                            <tr>
+                                <td><kbd>{shortcut.key}</kbd></td>
+                                <td>{shortcut.description}</td>
+                            </tr>
+                        }) }
+                        </tbody>
+                    </table>
+                </section>
+            </div>
+        </div>
+    }
+}
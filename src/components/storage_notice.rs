@@ -0,0 +1,24 @@
+use yew::prelude::*;
+use yew_hooks::use_local_storage;
+
+const DISMISSED_KEY: &str = "pandora.storage_notice_dismissed";
+
+/// One-time note that preferences and cached release data are kept in local
+/// storage. Sits in a corner so it never blocks the page
+#[function_component(StorageNotice)]
+pub fn storage_notice() -> Html {
+    let dismissed = use_local_storage::<bool>(DISMISSED_KEY.to_string());
+
+    if dismissed.unwrap_or(false) {
+        return Default::default();
+    }
+
+    let ondismiss = Callback::from(move |_| dismissed.set(true));
+
+    html! {
+        <div class="notification is-dark is-size-7 storage-notice" role="status">
+            <button class="delete is-small" aria-label="Dismiss" onclick={ondismiss}></button>
+            {"This site stores your preferences and cached release info in your browser's local storage. No tracking cookies are used."}
+        </div>
+    }
+}
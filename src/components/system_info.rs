@@ -0,0 +1,71 @@
+use yew::platform::spawn_local;
+use yew::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::config;
+use crate::platform::{detect_architecture, detect_browser, detect_os};
+use crate::services::cache;
+use crate::services::github::{GitHubReleases, LATEST_RELEASE_CACHE_KEY};
+
+/// The diagnostics block pasted into bug reports. Only coarse details go in:
+/// no full user agent, no storage contents beyond the Pandora version, nothing
+/// that identifies the visitor. The version is the tag of the last latest
+/// release this browser fetched
+fn system_info(architecture: Option<&str>) -> String {
+    let unknown = || "unknown".to_string();
+    let latest: Option<GitHubReleases> = cache::get(LATEST_RELEASE_CACHE_KEY, f64::INFINITY);
+    [
+        ("OS", detect_os().name().to_string()),
+        ("Architecture", architecture.map(str::to_string).unwrap_or_else(unknown)),
+        ("Browser", detect_browser().unwrap_or_else(unknown)),
+        ("Site build", config::GIT_HASH.filter(|hash| !hash.is_empty()).unwrap_or("development").to_string()),
+        ("Latest Pandora seen", latest.map(|release| release.tag_name.to_string()).unwrap_or_else(unknown)),
+    ]
+    .iter()
+    .map(|(label, value)| format!("{}: {}\n", label, value))
+    .collect()
+}
+
+/// "Copy system info" for bug reports. The first click shows exactly what will
+/// be copied, and copying is a separate, explicit step
+#[function_component(SystemInfo)]
+pub fn system_info_button() -> Html {
+    let open = use_state(|| false);
+    let architecture = use_state(|| None::<String>);
+
+    let ontoggle = {
+        let open = open.clone();
+        let architecture = architecture.clone();
+        Callback::from(move |_| {
+            if !*open && architecture.is_none() {
+                let architecture = architecture.clone();
+                spawn_local(async move {
+                    if let Some(detected) = detect_architecture().await {
+                        architecture.set(Some(format!("{} ({}-bit)", detected.architecture, detected.bitness)));
+                    }
+                });
+            }
+            open.set(!*open);
+        })
+    };
+
+    html! {
+        <div class="has-text-centered">
+            <button class="button is-small is-dark mt-2" aria-expanded={open.to_string()} onclick={ontoggle}>
+                { if *open { "Hide system info" } else { "Copy system info for bug reports" } }
+            </button>
+            if *open {
+                {{
+                    let text = system_info(architecture.as_deref());
+                    html! {
+                        <div class="system-info">
+                            <p class="is-size-7 has-text-grey">{"This is everything that will be copied:"}</p>
+                            <pre>{text.clone()}</pre>
+                            <CopyButton text={text} label="Copy to clipboard" title="Copy system info" classes="mt-2"/>
+                        </div>
+                    }
+                }}
+            }
+        </div>
+    }
+}
@@ -0,0 +1,179 @@
+use gloo::storage::{LocalStorage, Storage};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+use yew_hooks::{use_async, use_search_param};
+
+use crate::components::release_notes::ReleaseNotes;
+use crate::services::github::{fetch_all_releases, GitHubReleases};
+use crate::version::Version;
+
+pub const LAST_SEEN_VERSION_KEY: &str = "pandora.last_seen_version";
+/// Latest tag the banner was dismissed for. Also honoured with `?current=`, so
+/// a launcher that keeps linking here with an old version doesn't nag until
+/// an even newer release ships
+const DISMISSED_VERSION_KEY: &str = "pandora.update_dismissed_version";
+const FAVICON: &str = "/pandora_logo.svg";
+const UPDATE_FAVICON: &str = "/pandora_logo_update.svg";
+const TITLE_PREFIX: &str = "(update available) ";
+
+fn set_favicon(href: &str) {
+    let link = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.query_selector("link[rel~='icon']").ok().flatten())
+        .and_then(|link| link.dyn_into::<web_sys::HtmlLinkElement>().ok());
+    if let Some(link) = link {
+        link.set_href(href);
+    }
+}
+
+/// Adds or removes the "(update available)" title prefix and swaps to the badged favicon
+fn set_update_badge(active: bool) {
+    set_favicon(if active { UPDATE_FAVICON } else { FAVICON });
+
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        let title = document.title();
+        let title = title.strip_prefix(TITLE_PREFIX).unwrap_or(&title);
+        if active {
+            document.set_title(&format!("{}{}", TITLE_PREFIX, title));
+        } else {
+            document.set_title(title);
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ChangesSinceParams {
+    current: Version,
+    latest: Version,
+}
+
+/// Notes of every release after `current` up to and including `latest`,
+/// newest first. Only fetched once the visitor expands it
+#[function_component(ChangesSince)]
+fn changes_since(params: &ChangesSinceParams) -> Html {
+    let open = use_state(|| false);
+    let releases = use_async(fetch_all_releases());
+
+    let ontoggle = {
+        let open = open.clone();
+        let releases = releases.clone();
+        Callback::from(move |_| {
+            if !*open && releases.data.is_none() && !releases.loading {
+                releases.run();
+            }
+            open.set(!*open);
+        })
+    };
+
+    let content = if !*open {
+        Default::default()
+    } else if let Some(all) = &releases.data {
+        let mut between: Vec<_> = all.iter()
+            .filter_map(|release| Some((release.version()?, release)))
+            .filter(|(version, _)| *version > params.current && *version <= params.latest)
+            .collect();
+        between.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        html! {
+            <div class="changes-since">
+                { for between.into_iter().map(|(_, release)| html! {
+                    <div class="mt-3">
+                        <p class="has-text-weight-bold" title={release.tag_name.to_string()}>{release.display_name()}</p>
+                        {match release.body.as_deref().filter(|body| !body.trim().is_empty()) {
+                            Some(body) => html! { <ReleaseNotes body={body.to_string()}/> },
+                            None => html! { <p class="is-size-7">{"No release notes for this version"}</p> },
+                        }}
+                    </div>
+                }) }
+            </div>
+        }
+    } else if releases.error.is_some() {
+        html! { <p class="is-size-7 mt-2">{"Couldn't load the release notes"}</p> }
+    } else {
+        html! { <p class="is-size-7 mt-2">{"Loading…"}</p> }
+    };
+
+    html! {
+        <>
+        <button class="button is-small is-info is-inverted is-outlined mt-2" aria-expanded={open.to_string()} onclick={ontoggle}>
+            { if *open { "Hide changes" } else { "See what changed" } }
+        </button>
+        {content}
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct UpdateBannerParams {
+    pub latest: Option<GitHubReleases>,
+}
+
+/// Tells returning visitors that a newer version is out, comparing against
+/// `?current=` when the launcher links here, or the version they last dismissed
+#[function_component(UpdateBanner)]
+pub fn update_banner(params: &UpdateBannerParams) -> Html {
+    let current_param = use_search_param("current".to_string());
+    let last_seen = use_state(|| LocalStorage::get::<String>(LAST_SEEN_VERSION_KEY).ok());
+    let dismissed = use_state(|| LocalStorage::get::<String>(DISMISSED_VERSION_KEY).ok());
+
+    let latest_tag = params.latest.as_ref().map(|latest| latest.tag_name.to_string());
+    let current = current_param.or_else(|| (*last_seen).clone());
+
+    let update = match (&latest_tag, &current) {
+        (Some(latest), Some(current)) => {
+            let newer = match (latest.parse::<Version>(), current.parse::<Version>()) {
+                (Ok(latest), Ok(current)) => latest > current,
+                _ => false,
+            };
+            let dismissed = dismissed.as_deref() == Some(latest.as_str());
+            (newer && !dismissed).then(|| (latest.clone(), current.clone()))
+        },
+        _ => None,
+    };
+
+    {
+        let active = update.is_some();
+        use_effect_with_deps(move |active| {
+            set_update_badge(*active);
+            || set_update_badge(false)
+        }, active);
+    }
+
+    // First visit: remember the current latest so future releases can be flagged
+    {
+        let latest_tag = latest_tag.clone();
+        use_effect_with_deps(move |latest_tag| {
+            if let Some(latest) = latest_tag {
+                if LocalStorage::get::<String>(LAST_SEEN_VERSION_KEY).is_err() {
+                    let _ = LocalStorage::set(LAST_SEEN_VERSION_KEY, latest);
+                }
+            }
+        }, latest_tag);
+    }
+
+    let Some((latest, current)) = update else {
+        return Default::default();
+    };
+
+    let ondismiss = {
+        let latest = latest.clone();
+        Callback::from(move |_| {
+            let _ = LocalStorage::set(LAST_SEEN_VERSION_KEY, &latest);
+            let _ = LocalStorage::set(DISMISSED_VERSION_KEY, &latest);
+            last_seen.set(Some(latest.clone()));
+            dismissed.set(Some(latest.clone()));
+        })
+    };
+
+    html! {
+        <ybc::Notification classes="is-info update-banner">
+            <button class="delete" aria-label="Dismiss" onclick={ondismiss}></button>
+            {format!("Pandora {} is available — you're on {}", latest, current)}
+            if let (Ok(latest), Ok(current)) = (latest.parse::<Version>(), current.parse::<Version>()) {
+                <div>
+                    <ChangesSince {current} {latest}/>
+                </div>
+            }
+        </ybc::Notification>
+    }
+}
@@ -0,0 +1,120 @@
+//! Deployment settings, read from environment variables at build time
+
+/// Receives anonymous "detected vs chosen OS" pairs when a visitor overrides the
+/// detected platform. Reporting is disabled when unset
+pub const TELEMETRY_ENDPOINT: Option<&str> = option_env!("PANDORA_TELEMETRY_ENDPOINT");
+
+/// Self-hosted counter that receives a JSON `{"route": "..."}` POST per page
+/// view. No cookies or identifiers are sent. Disabled when unset
+pub const PAGEVIEW_ENDPOINT: Option<&str> = option_env!("PANDORA_PAGEVIEW_ENDPOINT");
+
+/// Push backend that receives Web Push subscriptions as JSON, and the VAPID
+/// public key it signs with. The "Notify me" button needs both
+pub const PUSH_ENDPOINT: Option<&str> = option_env!("PANDORA_PUSH_ENDPOINT");
+pub const VAPID_PUBLIC_KEY: Option<&str> = option_env!("PANDORA_VAPID_PUBLIC_KEY");
+
+/// URL scheme the launcher registers, e.g. `pandora`. Enables "Open in
+/// Pandora" buttons that hand `<scheme>://install?version=<tag>` to an
+/// installed launcher. Hidden when unset
+pub const LAUNCHER_SCHEME: Option<&str> = option_env!("PANDORA_LAUNCHER_SCHEME");
+
+/// Oldest macOS the builds run on, e.g. `10.13`. Visitors whose browser reports
+/// an older version are warned before downloading. No check when unset
+pub const MIN_MACOS_VERSION: Option<&str> = option_env!("PANDORA_MIN_MACOS_VERSION");
+
+/// Site-wide notice shown at the top of every page, e.g. during deploys or
+/// GitHub outages. No banner is shown when unset
+pub const MAINTENANCE_MESSAGE: Option<&str> = option_env!("PANDORA_MAINTENANCE_MESSAGE");
+
+/// Domain the official site is served from, stated in the footer so visitors
+/// can spot lookalike clones
+pub const CANONICAL_DOMAIN: &str = match option_env!("PANDORA_CANONICAL_DOMAIN") {
+    Some(domain) => domain,
+    None => "pandora.moulberry.com",
+};
+
+/// Commit and date of this site build, shown in the footer. Set by the deploy,
+/// e.g. `PANDORA_GIT_HASH=$(git rev-parse --short HEAD)`
+pub const GIT_HASH: Option<&str> = option_env!("PANDORA_GIT_HASH");
+pub const BUILD_DATE: Option<&str> = option_env!("PANDORA_BUILD_DATE");
+
+/// Seconds between background checks for a new release on the home page, for
+/// always-on status screens. Polling is off when unset or `0`
+const RELEASE_POLL_SECS: Option<&str> = option_env!("PANDORA_RELEASE_POLL_SECS");
+
+pub fn release_poll_interval_millis() -> u32 {
+    RELEASE_POLL_SECS
+        .and_then(|secs| secs.trim().parse::<u32>().ok())
+        .unwrap_or(0)
+        .saturating_mul(1000)
+}
+
+/// Recolour the tiles (`PANDORA_PRIMARY_COLOR`) and download buttons
+/// (`PANDORA_LINK_COLOR`) for forks or seasonal events, as any CSS colour.
+/// Applied at runtime through CSS custom properties, so styles aren't rebuilt
+pub const PRIMARY_COLOR: Option<&str> = option_env!("PANDORA_PRIMARY_COLOR");
+pub const LINK_COLOR: Option<&str> = option_env!("PANDORA_LINK_COLOR");
+
+/// Comma-separated `Name=https://base` pairs offered in the "Download mirror"
+/// selector. Mirrors replace the `https://github.com` prefix of asset URLs
+const DOWNLOAD_MIRRORS: Option<&str> = option_env!("PANDORA_DOWNLOAD_MIRRORS");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mirror {
+    pub name: &'static str,
+    pub base: &'static str,
+}
+
+pub fn download_mirrors() -> Vec<Mirror> {
+    DOWNLOAD_MIRRORS
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|entry| {
+            let (name, base) = entry.split_once('=')?;
+            Some(Mirror { name: name.trim(), base: base.trim().trim_end_matches('/') })
+        })
+        .filter(|mirror| !mirror.name.is_empty() && !mirror.base.is_empty())
+        .collect()
+}
+
+/// Per-deployment toggles for optional sections. Each is off unless its
+/// variable is set to `1` or `true`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureFlags {
+    /// `PANDORA_ENABLE_ANALYTICS`: anonymous beacons and the opt-out toggle
+    pub analytics: bool,
+    /// `PANDORA_ENABLE_BETA_CHANNEL`: the "Latest beta" card
+    pub beta_channel: bool,
+    /// `PANDORA_ENABLE_PREFETCH`: loading the full release list in the
+    /// background on the home page, so the version history opens instantly
+    pub prefetch_releases: bool,
+}
+
+impl FeatureFlags {
+    pub fn from_env() -> Self {
+        FeatureFlags {
+            analytics: flag(option_env!("PANDORA_ENABLE_ANALYTICS")),
+            beta_channel: flag(option_env!("PANDORA_ENABLE_BETA_CHANNEL")),
+            prefetch_releases: flag(option_env!("PANDORA_ENABLE_PREFETCH")),
+        }
+    }
+}
+
+fn flag(value: Option<&str>) -> bool {
+    matches!(value.map(str::trim), Some("1" | "true"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_are_off_unless_enabled() {
+        assert!(!flag(None));
+        assert!(!flag(Some("")));
+        assert!(!flag(Some("0")));
+        assert!(!flag(Some("yes")));
+        assert!(flag(Some("1")));
+        assert!(flag(Some(" true ")));
+    }
+}
@@ -0,0 +1,65 @@
+/// Whether the page was opened with `?debug=1`, which raises the log level and
+/// shows the diagnostics panel. Never on for normal visitors
+pub fn debug_enabled() -> bool {
+    web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get("debug"))
+        .is_some_and(|value| value == "1")
+}
+
+/// WCAG AA minimum for normal-sized text
+const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+/// Parses the `rgb(r, g, b)`/`rgba(r, g, b, a)` strings returned by `getComputedStyle`
+fn parse_rgb(color: &str) -> Option<[f64; 3]> {
+    let channels = color.split_once('(')?.1.trim_end_matches(')');
+    let mut channels = channels.split(',').map(|channel| channel.trim().parse::<f64>());
+    Some([channels.next()?.ok()?, channels.next()?.ok()?, channels.next()?.ok()?])
+}
+
+fn relative_luminance(rgb: [f64; 3]) -> f64 {
+    let linear = rgb.map(|channel| {
+        let channel = channel / 255.0;
+        if channel <= 0.03928 { channel / 12.92 } else { ((channel + 0.055) / 1.055).powf(2.4) }
+    });
+    0.2126 * linear[0] + 0.7152 * linear[1] + 0.0722 * linear[2]
+}
+
+pub fn contrast_ratio(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Logs the contrast between the primary tiles' background and their body and
+/// subtitle text, warning when either falls below WCAG AA
+pub fn log_tile_contrast() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(tile) = window.document().and_then(|document| document.query_selector(".notification.is-primary").ok().flatten()) else {
+        return;
+    };
+    let color_of = |element: &web_sys::Element, property: &str| {
+        window.get_computed_style(element).ok().flatten()
+            .and_then(|style| style.get_property_value(property).ok())
+            .and_then(|value| parse_rgb(&value))
+    };
+
+    let Some(background) = color_of(&tile, "background-color") else {
+        return;
+    };
+    let subtitle = tile.query_selector(".subtitle").ok().flatten();
+    let texts = [("body text", Some(tile.clone())), ("subtitle", subtitle)];
+    for (label, element) in texts.iter() {
+        let Some(foreground) = element.as_ref().and_then(|element| color_of(element, "color")) else {
+            continue;
+        };
+        let ratio = contrast_ratio(foreground, background);
+        if ratio < MIN_CONTRAST_RATIO {
+            log::warn!("Primary tile {} contrast is {:.2}:1, below {}:1", label, ratio, MIN_CONTRAST_RATIO);
+        } else {
+            log::debug!("Primary tile {} contrast is {:.2}:1", label, ratio);
+        }
+    }
+}
@@ -0,0 +1,513 @@
+use std::{collections::{BTreeMap, HashMap}, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::platform::OperatingSystem;
+use crate::preferences::PackagePreference;
+use crate::services::github::{GitHubReleaseAsset, GitHubReleases};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd)]
+pub enum DownloadType {
+    WindowsInstaller,
+    WindowsPortable,
+    LinuxDebianInstaller,
+    /// The `_arm64.deb`, published alongside the x64 one
+    LinuxDebianInstallerArm64,
+    LinuxAppImage,
+    LinuxPortable,
+    MacInstaller,
+    MacPortable,
+    /// A single package for every platform, if one is ever published
+    Universal,
+}
+
+impl DownloadType {
+    pub const ALL: [DownloadType; 9] = [
+        DownloadType::WindowsInstaller,
+        DownloadType::WindowsPortable,
+        DownloadType::LinuxDebianInstaller,
+        DownloadType::LinuxDebianInstallerArm64,
+        DownloadType::LinuxAppImage,
+        DownloadType::LinuxPortable,
+        DownloadType::MacInstaller,
+        DownloadType::MacPortable,
+        DownloadType::Universal,
+    ];
+
+    /// Stable identifier used as the key in `/api/latest.json`
+    pub fn id(self) -> &'static str {
+        match self {
+            DownloadType::WindowsInstaller => "windows-installer",
+            DownloadType::WindowsPortable => "windows-portable",
+            DownloadType::LinuxDebianInstaller => "linux-deb",
+            DownloadType::LinuxDebianInstallerArm64 => "linux-deb-arm64",
+            DownloadType::LinuxAppImage => "linux-appimage",
+            DownloadType::LinuxPortable => "linux-portable",
+            DownloadType::MacInstaller => "macos-installer",
+            DownloadType::MacPortable => "macos-portable",
+            DownloadType::Universal => "universal",
+        }
+    }
+
+    pub fn os(self) -> OperatingSystem {
+        match self {
+            DownloadType::WindowsInstaller | DownloadType::WindowsPortable => OperatingSystem::Windows,
+            DownloadType::LinuxDebianInstaller | DownloadType::LinuxDebianInstallerArm64 | DownloadType::LinuxAppImage | DownloadType::LinuxPortable => {
+                OperatingSystem::Linux
+            },
+            DownloadType::MacInstaller | DownloadType::MacPortable => OperatingSystem::MacOS,
+            DownloadType::Universal => OperatingSystem::Unknown,
+        }
+    }
+
+    pub fn is_portable(self) -> bool {
+        matches!(self, DownloadType::WindowsPortable | DownloadType::LinuxAppImage | DownloadType::LinuxPortable | DownloadType::MacPortable | DownloadType::Universal)
+    }
+
+    /// Short explanation of the installer vs portable tradeoff for this format
+    pub fn tradeoff(self) -> &'static str {
+        match self {
+            DownloadType::WindowsInstaller | DownloadType::MacInstaller | DownloadType::LinuxDebianInstaller | DownloadType::LinuxDebianInstallerArm64 => {
+                "Installs Pandora and integrates it with your OS. Auto-updates"
+            },
+            DownloadType::LinuxAppImage => "A single file that runs on most distros without installing",
+            DownloadType::Universal => "One package that works on every platform",
+            DownloadType::WindowsPortable | DownloadType::LinuxPortable | DownloadType::MacPortable => {
+                "Runs without installing, from wherever you put it"
+            },
+        }
+    }
+
+    pub fn recommendation_label(self) -> &'static str {
+        match self {
+            DownloadType::WindowsInstaller => "Download Windows Installer (.exe)",
+            DownloadType::WindowsPortable => "Download Windows Portable (.exe)",
+            DownloadType::LinuxDebianInstaller => "Download Linux Installer (.deb)",
+            DownloadType::LinuxDebianInstallerArm64 => "Download Linux Installer (.deb, ARM64)",
+            DownloadType::LinuxAppImage => "Download Linux AppImage",
+            DownloadType::LinuxPortable => "Download Linux Portable",
+            DownloadType::MacInstaller => "Download macOS Installer (.dmg)",
+            DownloadType::MacPortable => "Download macOS Portable",
+            DownloadType::Universal => "Download for all platforms",
+        }
+    }
+
+    /// Swaps in the ARM64 build of this format for ARM machines. Only the
+    /// `.deb` is published per architecture, everything else is returned as-is
+    pub fn for_architecture(self, arm64: bool) -> DownloadType {
+        match self {
+            DownloadType::LinuxDebianInstaller if arm64 => DownloadType::LinuxDebianInstallerArm64,
+            download_type => download_type,
+        }
+    }
+}
+
+/// Picks the download to suggest up-front for the visitor's OS. Linux only gets
+/// a suggestion for portable builds since the right installer depends on the distro
+pub fn recommended_download(os: OperatingSystem, preference: PackagePreference) -> Option<DownloadType> {
+    match (os, preference) {
+        (OperatingSystem::Windows, PackagePreference::Installer) => Some(DownloadType::WindowsInstaller),
+        (OperatingSystem::Windows, PackagePreference::Portable) => Some(DownloadType::WindowsPortable),
+        (OperatingSystem::MacOS, PackagePreference::Installer) => Some(DownloadType::MacInstaller),
+        (OperatingSystem::MacOS, PackagePreference::Portable) => Some(DownloadType::MacPortable),
+        (OperatingSystem::Linux, PackagePreference::Portable) => Some(DownloadType::LinuxPortable),
+        // Crostini is Debian-based, so the .deb is the installer that fits
+        (OperatingSystem::ChromeOS, PackagePreference::Installer) => Some(DownloadType::LinuxDebianInstaller),
+        (OperatingSystem::ChromeOS, PackagePreference::Portable) => Some(DownloadType::LinuxPortable),
+        _ => None,
+    }
+}
+
+/// Whether to offer ARM64 builds over x64 ones: only on an ARM64 CPU, and only
+/// when the release actually has an ARM64 build to offer
+pub fn prefer_arm64(arm64_cpu: bool, downloads: &DownloadsByType) -> bool {
+    arm64_cpu && downloads.contains_key(&DownloadType::LinuxDebianInstallerArm64)
+}
+
+/// One row of the installer vs portable comparison table
+pub struct PackageComparison {
+    pub feature: &'static str,
+    pub installer: bool,
+    pub portable: bool,
+}
+
+pub const PACKAGE_COMPARISON: [PackageComparison; 4] = [
+    PackageComparison { feature: "Auto-updates", installer: true, portable: false },
+    PackageComparison { feature: "Requires admin", installer: true, portable: false },
+    PackageComparison { feature: "Leaves no traces", installer: false, portable: true },
+    PackageComparison { feature: "Portable across machines", installer: false, portable: true },
+];
+
+/// A classified release asset
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Download {
+    pub url: Arc<str>,
+    pub name: Arc<str>,
+    pub size: u64,
+    /// Whether the build ships its own Java runtime, if the filename says either way
+    pub bundles_java: Option<bool>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Download {
+    pub fn from_asset(asset: &GitHubReleaseAsset) -> Self {
+        Download {
+            url: asset.browser_download_url.clone(),
+            name: asset.name.clone(),
+            size: asset.size,
+            bundles_java: bundles_java(&asset.name),
+            updated_at: asset.updated_at,
+        }
+    }
+}
+
+pub type DownloadsByType = HashMap<DownloadType, Download>;
+
+/// Stable shape served at `/api/latest.json` for scripts that shouldn't have to
+/// parse GitHub's release format
+#[derive(Debug, Serialize)]
+pub struct LatestSummary {
+    pub version: Arc<str>,
+    pub downloads: BTreeMap<&'static str, Download>,
+}
+
+impl LatestSummary {
+    pub fn from_release(release: &GitHubReleases) -> Self {
+        LatestSummary {
+            version: release.tag_name.clone(),
+            downloads: downloads_by_type(release)
+                .into_iter()
+                .map(|(download_type, download)| (download_type.id(), download))
+                .collect(),
+        }
+    }
+}
+
+/// The download tiles shown per platform, in display order
+pub struct PlatformDownloads {
+    pub os: OperatingSystem,
+    pub title: &'static str,
+    pub formats: &'static [(&'static str, DownloadType)],
+}
+
+pub const PLATFORMS: [PlatformDownloads; 3] = [
+    PlatformDownloads {
+        os: OperatingSystem::Windows,
+        title: "Windows x64",
+        formats: &[
+            ("Installer .exe", DownloadType::WindowsInstaller),
+            ("Portable Executable .exe", DownloadType::WindowsPortable),
+        ],
+    },
+    PlatformDownloads {
+        os: OperatingSystem::Linux,
+        title: "Linux",
+        formats: &[
+            ("Debian Installer .deb", DownloadType::LinuxDebianInstaller),
+            ("Debian Installer .deb (ARM64)", DownloadType::LinuxDebianInstallerArm64),
+            ("AppImage .AppImage", DownloadType::LinuxAppImage),
+            ("Portable Executable", DownloadType::LinuxPortable),
+        ],
+    },
+    PlatformDownloads {
+        os: OperatingSystem::MacOS,
+        title: "macOS",
+        formats: &[
+            ("Installer .dmg", DownloadType::MacInstaller),
+            ("Portable Executable", DownloadType::MacPortable),
+        ],
+    },
+];
+
+/// `PLATFORMS` with the visitor's own platform moved to the front. The rest keep
+/// their usual order
+pub fn platforms_for(os: OperatingSystem) -> Vec<&'static PlatformDownloads> {
+    let os = if os == OperatingSystem::ChromeOS { OperatingSystem::Linux } else { os };
+    let mut platforms: Vec<_> = PLATFORMS.iter().collect();
+    platforms.sort_by_key(|platform| platform.os != os);
+    platforms
+}
+
+/// The one format a platform's tile shows while advanced downloads are closed:
+/// the recommended one for the preference, or the tile's first format. When the
+/// release lacks that build but has another for the platform, the first
+/// available format stands in, so the collapsed tile never offers a dead button
+pub fn collapsed_format(platform: &PlatformDownloads, preference: PackagePreference, arm64: bool, downloads: &DownloadsByType) -> Option<DownloadType> {
+    let preferred = recommended_download(platform.os, preference)
+        .or_else(|| platform.formats.first().map(|&(_, download_type)| download_type))
+        .map(|download_type| download_type.for_architecture(arm64));
+    match preferred {
+        Some(download_type) if !downloads.contains_key(&download_type) => platform.formats.iter()
+            .map(|&(_, download_type)| download_type)
+            .find(|download_type| downloads.contains_key(download_type))
+            .or(preferred),
+        preferred => preferred,
+    }
+}
+
+/// Markdown table of every available download, for pasting into forums and chats
+pub fn downloads_markdown(downloads: &DownloadsByType) -> String {
+    let mut markdown = String::from("| Platform | Format | Link |\n| --- | --- | --- |\n");
+    for platform in PLATFORMS.iter() {
+        for (label, download_type) in platform.formats.iter() {
+            if let Some(download) = downloads.get(download_type) {
+                markdown.push_str(&format!("| {} | {} | [{}]({}) |\n", platform.title, label, download.name, download.url));
+            }
+        }
+    }
+    markdown
+}
+
+/// Name markers that make an `.exe` the installer rather than the portable build
+const EXE_INSTALLER_MARKERS: &[&str] = &["-setup", "-installer"];
+
+/// Name markers of the ARM64 `.deb`
+const DEB_ARM64_MARKERS: &[&str] = &["_arm64", "-arm64", "aarch64"];
+
+/// An `.exe` is the installer exactly when it carries an installer marker, and
+/// portable otherwise, so no arm ordering decides between the two
+fn windows_exe_type(is_installer: bool) -> DownloadType {
+    if is_installer {
+        DownloadType::WindowsInstaller
+    } else {
+        DownloadType::WindowsPortable
+    }
+}
+
+/// Classifies an asset by its extension first and then by markers anywhere in
+/// the name, so extra segments such as CI commit hashes
+/// (`Pandora-1.2.3-ab12cd3-setup.exe`, `Pandora-setup-ab12cd3.exe`) don't matter
+pub fn classify_asset(name: &str) -> Option<DownloadType> {
+    let name = name.to_ascii_lowercase();
+    let extension = name.rsplit_once('.').map(|(_, extension)| extension).unwrap_or_default();
+    let has_marker = |markers: &[&str]| markers.iter().any(|marker| name.contains(marker));
+
+    if has_marker(&["-all-platforms"]) {
+        return Some(DownloadType::Universal);
+    }
+
+    match extension {
+        "dmg" => Some(DownloadType::MacInstaller),
+        "appimage" => Some(DownloadType::LinuxAppImage),
+        // Debian names packages `<name>_<version>_<arch>.deb`, anything not ARM is x64
+        "deb" if has_marker(DEB_ARM64_MARKERS) => Some(DownloadType::LinuxDebianInstallerArm64),
+        "deb" => Some(DownloadType::LinuxDebianInstaller),
+        "msi" => Some(DownloadType::WindowsInstaller),
+        "exe" => Some(windows_exe_type(has_marker(EXE_INSTALLER_MARKERS))),
+        _ if has_marker(&["-macos"]) => Some(DownloadType::MacPortable),
+        _ if has_marker(&["-linux"]) => Some(DownloadType::LinuxPortable),
+        // Checked last, since a macOS universal binary is still a macOS build
+        _ if has_marker(&["-universal"]) => Some(DownloadType::Universal),
+        _ => None,
+    }
+}
+
+/// Rewrites a GitHub asset URL to go through the given mirror base, if any
+pub fn mirror_url(original: &str, mirror: Option<&str>) -> String {
+    match (mirror, original.strip_prefix("https://github.com")) {
+        (Some(mirror), Some(path)) => format!("{}{}", mirror, path),
+        _ => original.to_string(),
+    }
+}
+
+/// Host part of an absolute URL, e.g. `github.com`, and whether it is served over HTTPS
+pub fn url_host(url: &str) -> Option<(&str, bool)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let host = rest.split(['/', '?', '#']).next().filter(|host| !host.is_empty())?;
+    Some((host, scheme.eq_ignore_ascii_case("https")))
+}
+
+/// GitHub itself or its release CDN, as opposed to a mirror
+pub fn is_github_host(host: &str) -> bool {
+    host == "github.com" || host.ends_with(".github.com") || host.ends_with(".githubusercontent.com")
+}
+
+/// Downloads at least this big get the "Resumable" hint
+pub const LARGE_DOWNLOAD_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Whether to tell the visitor a download can be resumed. Browsers can't check
+/// `Accept-Ranges` on GitHub's downloads because of CORS, so this goes by the
+/// API's size and host: GitHub's release CDN serves byte ranges, while a
+/// mirror's support is unknown. `href` is the link actually served, after any
+/// mirror rewrite
+pub fn resume_hint(size: u64, href: &str) -> bool {
+    size >= LARGE_DOWNLOAD_BYTES && url_host(href).is_some_and(|(host, _)| is_github_host(host))
+}
+
+/// Reads whether a build bundles Java from markers like `-jre` or `-nojre` in its filename
+pub fn bundles_java(name: &str) -> Option<bool> {
+    let name = name.to_ascii_lowercase();
+    if ["-nojre", "-no-jre", "-without-java"].iter().any(|marker| name.contains(marker)) {
+        Some(false)
+    } else if ["-jre", "-with-java"].iter().any(|marker| name.contains(marker)) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+pub fn downloads_by_type(release: &GitHubReleases) -> DownloadsByType {
+    let mut releases_by_type = HashMap::new();
+
+    for asset in &release.assets {
+        if !asset.is_uploaded() {
+            log::info!("Skipping asset {} in state {}", &asset.name, &asset.state);
+            continue;
+        }
+
+        let Some(download_type) = classify_asset(&asset.name) else {
+            log::info!("Unknown download type for filename: {}", &asset.name);
+            continue;
+        };
+
+        releases_by_type.insert(download_type, Download::from_asset(asset));
+    }
+
+    releases_by_type
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::release_with;
+
+    #[test]
+    fn classifies_by_extension() {
+        let cases = [
+            ("Pandora-1.2.3-setup.exe", Some(DownloadType::WindowsInstaller)),
+            ("Pandora-1.2.3.msi", Some(DownloadType::WindowsInstaller)),
+            ("Pandora-1.2.3.dmg", Some(DownloadType::MacInstaller)),
+            ("Pandora-1.2.3-x86_64.AppImage", Some(DownloadType::LinuxAppImage)),
+            ("pandora_1.2.3_amd64.deb", Some(DownloadType::LinuxDebianInstaller)),
+            ("Pandora-1.2.3-linux", Some(DownloadType::LinuxPortable)),
+            ("Pandora-1.2.3-macos", Some(DownloadType::MacPortable)),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(classify_asset(name), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn ignores_case_and_commit_hashes() {
+        let cases = [
+            ("PANDORA-1.2.3-SETUP.EXE", Some(DownloadType::WindowsInstaller)),
+            ("Pandora-1.2.3-ab12cd3-setup.exe", Some(DownloadType::WindowsInstaller)),
+            ("Pandora-setup-ab12cd3.exe", Some(DownloadType::WindowsInstaller)),
+            ("Pandora-1.2.3-ab12cd3.DMG", Some(DownloadType::MacInstaller)),
+            ("Pandora-1.2.3-ab12cd3.appimage", Some(DownloadType::LinuxAppImage)),
+            ("Pandora-1.2.3-ab12cd3-Linux", Some(DownloadType::LinuxPortable)),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(classify_asset(name), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn exe_is_installer_only_with_a_marker() {
+        let cases = [
+            ("Pandora-1.2.3-setup.exe", DownloadType::WindowsInstaller),
+            ("Pandora-1.2.3-installer.exe", DownloadType::WindowsInstaller),
+            ("Pandora-1.2.3-portable.exe", DownloadType::WindowsPortable),
+            ("Pandora-1.2.3.exe", DownloadType::WindowsPortable),
+            ("Pandora.exe", DownloadType::WindowsPortable),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(classify_asset(name), Some(expected), "{}", name);
+        }
+        assert_eq!(windows_exe_type(true), DownloadType::WindowsInstaller);
+        assert_eq!(windows_exe_type(false), DownloadType::WindowsPortable);
+    }
+
+    #[test]
+    fn debs_are_classified_per_architecture() {
+        let cases = [
+            ("pandora_1.2.3_amd64.deb", DownloadType::LinuxDebianInstaller),
+            ("Pandora-1.2.3.deb", DownloadType::LinuxDebianInstaller),
+            ("pandora_1.2.3_arm64.deb", DownloadType::LinuxDebianInstallerArm64),
+            ("Pandora-1.2.3-ARM64.deb", DownloadType::LinuxDebianInstallerArm64),
+            ("pandora-1.2.3-aarch64.deb", DownloadType::LinuxDebianInstallerArm64),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(classify_asset(name), Some(expected), "{}", name);
+        }
+    }
+
+    #[test]
+    fn both_debs_are_kept() {
+        let downloads = downloads_by_type(&release_with("1.2.3", &["pandora_1.2.3_amd64.deb", "pandora_1.2.3_arm64.deb"]));
+        assert_eq!(&*downloads[&DownloadType::LinuxDebianInstaller].name, "pandora_1.2.3_amd64.deb");
+        assert_eq!(&*downloads[&DownloadType::LinuxDebianInstallerArm64].name, "pandora_1.2.3_arm64.deb");
+    }
+
+    #[test]
+    fn arm64_deb_is_preferred_on_arm64_cpus() {
+        let both = downloads_by_type(&release_with("1.2.3", &["pandora_1.2.3_amd64.deb", "pandora_1.2.3_arm64.deb"]));
+        let x64_only = downloads_by_type(&release_with("1.2.3", &["pandora_1.2.3_amd64.deb"]));
+        assert!(prefer_arm64(true, &both));
+        assert!(!prefer_arm64(false, &both));
+        assert!(!prefer_arm64(true, &x64_only));
+
+        let recommended = |arm64| recommended_download(OperatingSystem::ChromeOS, PackagePreference::Installer)
+            .map(|download_type| download_type.for_architecture(arm64));
+        assert_eq!(recommended(prefer_arm64(true, &both)), Some(DownloadType::LinuxDebianInstallerArm64));
+        assert_eq!(recommended(prefer_arm64(true, &x64_only)), Some(DownloadType::LinuxDebianInstaller));
+        assert_eq!(DownloadType::LinuxAppImage.for_architecture(true), DownloadType::LinuxAppImage);
+    }
+
+    #[test]
+    fn assets_still_uploading_are_skipped() {
+        let release: GitHubReleases = serde_json::from_value(serde_json::json!({
+            "tag_name": "1.2.3",
+            "assets": [
+                { "name": "Pandora-1.2.3-setup.exe", "browser_download_url": "https://github.com/a", "state": "uploaded" },
+                { "name": "Pandora-1.2.3.dmg", "browser_download_url": "https://github.com/b", "state": "starter" },
+                { "name": "Pandora-1.2.3.AppImage", "browser_download_url": "https://github.com/c", "state": "uploading" },
+                { "name": "pandora_1.2.3_amd64.deb", "browser_download_url": "https://github.com/d" },
+            ],
+        })).unwrap();
+
+        let downloads = downloads_by_type(&release);
+        let mut found: Vec<_> = downloads.keys().map(|download_type| download_type.id()).collect();
+        found.sort_unstable();
+        // An asset without a state counts as uploaded
+        assert_eq!(found, ["linux-deb", "windows-installer"]);
+    }
+
+    #[test]
+    fn resume_hint_needs_a_large_github_download() {
+        let github = "https://github.com/Moulberry/PandoraLauncher/releases/download/1.2.3/Pandora.dmg";
+        assert!(resume_hint(LARGE_DOWNLOAD_BYTES, github));
+        assert!(!resume_hint(LARGE_DOWNLOAD_BYTES - 1, github));
+        assert!(!resume_hint(LARGE_DOWNLOAD_BYTES, "https://mirror.example.com/Pandora.dmg"));
+    }
+
+    #[test]
+    fn mirrored_downloads_get_no_resume_hint() {
+        let github = "https://github.com/Moulberry/PandoraLauncher/releases/download/1.2.3/Pandora.dmg";
+        let mirrored = mirror_url(github, Some("https://mirror.example.com"));
+        assert_eq!(mirrored, "https://mirror.example.com/Moulberry/PandoraLauncher/releases/download/1.2.3/Pandora.dmg");
+        assert!(!resume_hint(LARGE_DOWNLOAD_BYTES, &mirrored));
+        assert!(resume_hint(LARGE_DOWNLOAD_BYTES, &mirror_url(github, None)));
+    }
+
+    #[test]
+    fn collapsed_tiles_fall_back_to_an_available_format() {
+        let linux = platforms_for(OperatingSystem::Linux)[0];
+        let all = downloads_by_type(&release_with("1.2.3", &["pandora_1.2.3_amd64.deb", "Pandora-1.2.3-x86_64.AppImage"]));
+        let no_deb = downloads_by_type(&release_with("1.2.3", &["Pandora-1.2.3-x86_64.AppImage"]));
+        let installer = PackagePreference::Installer;
+
+        assert_eq!(collapsed_format(linux, installer, false, &all), Some(DownloadType::LinuxDebianInstaller));
+        assert_eq!(collapsed_format(linux, installer, false, &no_deb), Some(DownloadType::LinuxAppImage));
+        // While nothing has loaded the preferred format keeps its placeholder
+        assert_eq!(collapsed_format(linux, installer, false, &DownloadsByType::new()), Some(DownloadType::LinuxDebianInstaller));
+    }
+
+    #[test]
+    fn unknown_assets_are_unclassified() {
+        for name in ["checksums.txt", "Pandora-1.2.3.zip.sha256", "Pandora-1.2.3.tar.gz", "README"] {
+            assert_eq!(classify_asset(name), None, "{}", name);
+        }
+    }
+}
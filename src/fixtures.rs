@@ -0,0 +1,16 @@
+//! Release data shared by the native and the browser tests
+
+use crate::services::github::GitHubReleases;
+
+/// A release of the given version whose assets are the given file names, each
+/// downloadable from `download_url(name)`
+pub fn release_with(tag: &str, names: &[&str]) -> GitHubReleases {
+    let assets: Vec<_> = names.iter()
+        .map(|name| serde_json::json!({ "name": name, "browser_download_url": download_url(name) }))
+        .collect();
+    serde_json::from_value(serde_json::json!({ "tag_name": tag, "assets": assets })).unwrap()
+}
+
+pub fn download_url(name: &str) -> String {
+    format!("https://github.com/download/{}", name)
+}
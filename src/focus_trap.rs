@@ -0,0 +1,91 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+use yew::prelude::*;
+use yew_hooks::use_event;
+
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), \
+    textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+fn active_element() -> Option<HtmlElement> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element())
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+}
+
+fn focusable_within(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Keeps Tab focus inside the returned node while `open`, closes on Escape, and
+/// hands focus back to whatever had it before opening once closed again
+#[hook]
+pub fn use_focus_trap(open: bool, onclose: Callback<()>) -> NodeRef {
+    let container = use_node_ref();
+
+    {
+        let container = container.clone();
+        use_effect_with_deps(move |open| {
+            let mut restore = None;
+            if *open {
+                restore = active_element();
+                let first = container.cast::<Element>().and_then(|container| focusable_within(&container).into_iter().next());
+                if let Some(first) = first {
+                    let _ = first.focus();
+                }
+            }
+            move || {
+                if let Some(restore) = restore {
+                    let _ = restore.focus();
+                }
+            }
+        }, open);
+    }
+
+    {
+        let trap = container.clone();
+        use_event(container.clone(), "keydown", move |event: KeyboardEvent| {
+            if !open {
+                return;
+            }
+            match event.key().as_str() {
+                "Escape" => {
+                    event.prevent_default();
+                    onclose.emit(());
+                },
+                "Tab" => {
+                    let Some(container) = trap.cast::<Element>() else {
+                        return;
+                    };
+                    let focusable = focusable_within(&container);
+                    let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+                        event.prevent_default();
+                        return;
+                    };
+
+                    let active = active_element();
+                    let wrap_to = if event.shift_key() && active.as_ref() == Some(first) {
+                        Some(last)
+                    } else if !event.shift_key() && active.as_ref() == Some(last) {
+                        Some(first)
+                    } else {
+                        None
+                    };
+                    if let Some(target) = wrap_to {
+                        event.prevent_default();
+                        let _ = target.focus();
+                    }
+                },
+                _ => {},
+            }
+        });
+    }
+
+    container
+}
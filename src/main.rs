@@ -4,21 +4,86 @@ use console_error_panic_hook::set_once as set_panic_hook;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+mod components;
+mod config;
+mod debug;
+mod downloads;
+#[cfg(test)]
+mod fixtures;
+mod focus_trap;
+mod platform;
+mod preferences;
 mod routes;
 mod services;
+mod shortcuts;
+#[cfg(all(test, target_arch = "wasm32"))]
+mod testing;
+mod theme;
+mod util;
+mod version;
+
+/// Everything inside the router. The embeddable widget and the JSON summary
+/// are shown without the banners and footer, so the widget fits in a small
+/// iframe and the JSON is all that's on the page
+#[function_component(Page)]
+fn page() -> Html {
+    let route = use_route::<routes::AppRoute>();
+    let bare = matches!(route, Some(routes::AppRoute::Embed | routes::AppRoute::LatestJson));
+
+    html! {
+        <>
+        if !bare {
+            <components::maintenance_banner::MaintenanceBanner />
+        }
+        <routes::PageviewCounter />
+        <Switch<routes::AppRoute> render={routes::switch} />
+        if !bare {
+            <components::footer::SiteFooter />
+            <components::storage_notice::StorageNotice />
+        }
+        </>
+    }
+}
 
 #[function_component(App)]
 pub fn app() -> Html {
+    let mirror = preferences::use_mirror_preference();
+    let recent_downloads = preferences::use_recent_downloads();
+    let backend_status = services::reachability::use_backend_status();
+    let feature_flags = use_memo(|_| config::FeatureFlags::from_env(), ());
+
     html! {
-        <BrowserRouter>
-            <Switch<routes::AppRoute> render={routes::switch} />
-        </BrowserRouter>
+        <ContextProvider<config::FeatureFlags> context={*feature_flags}>
+        <ContextProvider<services::reachability::BackendStatus> context={backend_status}>
+        <ContextProvider<preferences::MirrorPreference> context={mirror}>
+        <ContextProvider<preferences::RecentDownloads> context={recent_downloads}>
+            <BrowserRouter>
+                <Page />
+            </BrowserRouter>
+        </ContextProvider<preferences::RecentDownloads>>
+        </ContextProvider<preferences::MirrorPreference>>
+        </ContextProvider<services::reachability::BackendStatus>>
+        </ContextProvider<config::FeatureFlags>>
+    }
+}
+
+/// Removes the static HTML shown to browsers that can't run the app
+fn remove_static_fallback() {
+    let fallback = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id("static-fallback"));
+    if let Some(fallback) = fallback {
+        fallback.remove();
     }
 }
 
 fn main() {
     set_panic_hook();
-    wasm_logger::init(wasm_logger::Config::default());
+    let log_level = if debug::debug_enabled() { log::Level::Trace } else { log::Level::Info };
+    wasm_logger::init(wasm_logger::Config::new(log_level));
 
+    remove_static_fallback();
+    theme::apply_color_overrides();
+    theme::apply_stored_density();
     yew::Renderer::<App>::new().render();
 }
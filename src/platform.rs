@@ -0,0 +1,221 @@
+use js_sys::{Array, Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::version::Version;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatingSystem {
+    Windows,
+    Linux,
+    MacOS,
+    /// Runs the Linux builds inside the Crostini Linux environment
+    ChromeOS,
+    Unknown,
+}
+
+impl OperatingSystem {
+    pub const SELECTABLE: [OperatingSystem; 4] = [OperatingSystem::Windows, OperatingSystem::MacOS, OperatingSystem::Linux, OperatingSystem::ChromeOS];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            OperatingSystem::Windows => "windows",
+            OperatingSystem::Linux => "linux",
+            OperatingSystem::MacOS => "macos",
+            OperatingSystem::ChromeOS => "chromeos",
+            OperatingSystem::Unknown => "unknown",
+        }
+    }
+
+    pub fn from_id(id: &str) -> OperatingSystem {
+        Self::SELECTABLE.iter().copied().find(|os| os.id() == id).unwrap_or(OperatingSystem::Unknown)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            OperatingSystem::Windows => "Windows",
+            OperatingSystem::Linux => "Linux",
+            OperatingSystem::MacOS => "macOS",
+            OperatingSystem::ChromeOS => "ChromeOS",
+            OperatingSystem::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Detects the visitor's operating system, preferring the `userAgentData`
+/// client hint over the user-agent string, which Chromium is phasing out.
+/// Falls back to `Unknown` when there is no browser window to ask (e.g.
+/// outside of a browser)
+pub fn detect_os() -> OperatingSystem {
+    let from_client_hints = user_agent_data()
+        .and_then(|data| Reflect::get(&data, &JsValue::from_str("platform")).ok())
+        .and_then(|platform| platform.as_string())
+        .map(|platform| os_from_platform_hint(&platform))
+        .filter(|os| *os != OperatingSystem::Unknown);
+    from_client_hints.unwrap_or_else(|| {
+        let user_agent = web_sys::window().and_then(|window| window.navigator().user_agent().ok());
+        os_from_user_agent(user_agent.as_deref())
+    })
+}
+
+/// `navigator.userAgentData`, only present in Chromium-based browsers
+fn user_agent_data() -> Option<JsValue> {
+    let navigator = web_sys::window()?.navigator();
+    let data = Reflect::get(&navigator, &JsValue::from_str("userAgentData")).ok()?;
+    (!data.is_undefined() && !data.is_null()).then_some(data)
+}
+
+/// Maps the `Sec-CH-UA-Platform` values; anything else is left to the user-agent string
+fn os_from_platform_hint(platform: &str) -> OperatingSystem {
+    match platform {
+        "Windows" => OperatingSystem::Windows,
+        "macOS" => OperatingSystem::MacOS,
+        "Chrome OS" | "ChromeOS" => OperatingSystem::ChromeOS,
+        "Linux" => OperatingSystem::Linux,
+        _ => OperatingSystem::Unknown,
+    }
+}
+
+/// CPU details from the high-entropy client hints, e.g. `x86`/`64` or `arm`/`64`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Architecture {
+    pub architecture: String,
+    pub bitness: String,
+}
+
+impl Architecture {
+    pub fn is_arm64(&self) -> bool {
+        self.architecture == "arm" && self.bitness == "64"
+    }
+}
+
+/// Asks the browser for the CPU architecture. This resolves asynchronously, so
+/// run it after the first render. `None` outside Chromium or if the browser
+/// declines to answer
+pub async fn detect_architecture() -> Option<Architecture> {
+    let data = user_agent_data()?;
+    let get_high_entropy_values = Reflect::get(&data, &JsValue::from_str("getHighEntropyValues")).ok()?
+        .dyn_into::<Function>().ok()?;
+    let hints = Array::of2(&JsValue::from_str("architecture"), &JsValue::from_str("bitness"));
+    let promise = get_high_entropy_values.call1(&data, &hints).ok()?.dyn_into::<Promise>().ok()?;
+    let values = JsFuture::from(promise).await.ok()?;
+
+    let field = |name: &str| Reflect::get(&values, &JsValue::from_str(name)).ok()?.as_string();
+    Some(Architecture {
+        architecture: field("architecture")?,
+        bitness: field("bitness").unwrap_or_default(),
+    })
+}
+
+/// Browser name and major version, e.g. `Firefox 128`, without the rest of the user agent
+pub fn detect_browser() -> Option<String> {
+    let user_agent = web_sys::window()?.navigator().user_agent().ok()?;
+    browser_from_user_agent(&user_agent)
+}
+
+/// Checked in order, since Chromium forks also claim `Chrome/` and every
+/// Chromium browser claims `Safari/`
+const BROWSER_TOKENS: [(&str, &str); 7] = [
+    ("Edg/", "Edge"),
+    ("OPR/", "Opera"),
+    ("FxiOS/", "Firefox"),
+    ("Firefox/", "Firefox"),
+    ("CriOS/", "Chrome"),
+    ("Chrome/", "Chrome"),
+    ("Version/", "Safari"),
+];
+
+pub fn browser_from_user_agent(user_agent: &str) -> Option<String> {
+    BROWSER_TOKENS.iter().find_map(|(token, name)| {
+        let (_, rest) = user_agent.split_once(token)?;
+        let major: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        Some(if major.is_empty() { name.to_string() } else { format!("{} {}", name, major) })
+    })
+}
+
+pub fn os_from_user_agent(user_agent: Option<&str>) -> OperatingSystem {
+    let Some(user_agent) = user_agent else {
+        return OperatingSystem::Unknown;
+    };
+
+    // ChromeOS reports an X11 platform, so check it before anything Linux-like
+    if user_agent.contains("CrOS") {
+        OperatingSystem::ChromeOS
+    } else if user_agent.contains("Mac") {
+        OperatingSystem::MacOS
+    } else if user_agent.contains("Win") {
+        OperatingSystem::Windows
+    } else if user_agent.contains("Linux") {
+        OperatingSystem::Linux
+    } else {
+        OperatingSystem::Unknown
+    }
+}
+
+/// The visitor's macOS version, when the user agent still reveals it
+pub fn detect_macos_version() -> Option<Version> {
+    let user_agent = web_sys::window().and_then(|window| window.navigator().user_agent().ok())?;
+    macos_version_from_user_agent(&user_agent)
+}
+
+/// Reads the `Mac OS X 10_14_6` token. Browsers froze it at 10.15 for every
+/// later release, so 10.15 and up can't be told apart and count as unknown
+pub fn macos_version_from_user_agent(user_agent: &str) -> Option<Version> {
+    let (_, rest) = user_agent.split_once("Mac OS X ")?;
+    let token = rest.split(|c: char| c == ';' || c == ')' || c.is_whitespace()).next()?;
+    let version: Version = token.replace('_', ".").parse().ok()?;
+    (version < Version { major: 10, minor: 15, patch: 0, pre: Vec::new() }).then_some(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_user_agent_is_unknown() {
+        assert_eq!(os_from_user_agent(None), OperatingSystem::Unknown);
+    }
+
+    #[test]
+    fn user_agents() {
+        let cases = [
+            ("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36", OperatingSystem::Windows),
+            ("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15", OperatingSystem::MacOS),
+            ("Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0", OperatingSystem::Linux),
+            ("Mozilla/5.0 (X11; CrOS x86_64 14541.0.0) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36", OperatingSystem::ChromeOS),
+            ("curl/8.5.0", OperatingSystem::Unknown),
+        ];
+        for (user_agent, expected) in cases {
+            assert_eq!(os_from_user_agent(Some(user_agent)), expected, "{}", user_agent);
+        }
+    }
+
+    #[test]
+    fn macos_versions() {
+        let mojave = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_14_6) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/14.1.2 Safari/605.1.15";
+        assert_eq!(macos_version_from_user_agent(mojave), "10.14.6".parse().ok());
+        // Frozen at 10_15_7 on every later release
+        let frozen = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15";
+        assert_eq!(macos_version_from_user_agent(frozen), None);
+        assert_eq!(macos_version_from_user_agent("Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0"), None);
+    }
+
+    #[test]
+    fn browsers() {
+        let cases = [
+            // Edge and Opera also claim Chrome and Safari, so their own tokens win
+            ("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36 Edg/126.0.2592.87", Some("Edge 126")),
+            ("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36 OPR/111.0.0.0", Some("Opera 111")),
+            // Firefox on iOS is WebKit with a Version/ token of its own
+            ("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) FxiOS/127.0 Mobile/15E148 Version/17.5 Safari/605.1.15", Some("Firefox 127")),
+            ("Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0", Some("Firefox 128")),
+            ("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/126.0.6478.54 Mobile/15E148 Safari/604.1", Some("Chrome 126")),
+            ("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36", Some("Chrome 126")),
+            ("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15", Some("Safari 17")),
+            ("curl/8.5.0", None),
+        ];
+        for (user_agent, expected) in cases {
+            assert_eq!(browser_from_user_agent(user_agent).as_deref(), expected, "{}", user_agent);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_hooks::{use_local_storage, use_session_storage, UseLocalStorageHandle, UseSessionStorageHandle};
+
+use crate::config;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PackagePreference {
+    #[default]
+    Installer,
+    Portable,
+}
+
+/// Spacing of the download tiles and sections
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Density {
+    #[default]
+    Comfortable,
+    Compact,
+}
+
+impl Density {
+    /// Value of the root `data-density` attribute that index.scss keys off
+    pub fn id(self) -> &'static str {
+        match self {
+            Density::Comfortable => "comfortable",
+            Density::Compact => "compact",
+        }
+    }
+}
+
+pub const DENSITY_KEY: &str = "pandora.density";
+
+#[hook]
+pub fn use_density() -> UseLocalStorageHandle<Density> {
+    use_local_storage(DENSITY_KEY.to_string())
+}
+
+#[hook]
+pub fn use_package_preference() -> UseLocalStorageHandle<PackagePreference> {
+    use_local_storage("pandora.package_preference".to_string())
+}
+
+/// Whether the portable and other secondary builds are shown under each platform
+#[hook]
+pub fn use_advanced_downloads_open() -> UseLocalStorageHandle<bool> {
+    use_local_storage("pandora.advanced_downloads_open".to_string())
+}
+
+const AUTO_REFRESH_DISABLED_KEY: &str = "pandora.auto_refresh_disabled";
+
+/// Set by visitors on metered connections who only want data fetched on load
+/// or when they ask for it
+#[hook]
+pub fn use_auto_refresh_disabled() -> UseLocalStorageHandle<bool> {
+    use_local_storage(AUTO_REFRESH_DISABLED_KEY.to_string())
+}
+
+/// Checked before any background re-fetch. Reads storage directly so it always
+/// sees the latest toggle state
+pub fn auto_refresh_enabled() -> bool {
+    !LocalStorage::get::<bool>(AUTO_REFRESH_DISABLED_KEY).unwrap_or(false)
+}
+
+/// Base URL of the download mirror picked by the visitor, shared through context
+/// so every download link follows the selector
+#[derive(Clone, PartialEq)]
+pub struct MirrorPreference(pub UseLocalStorageHandle<String>);
+
+impl MirrorPreference {
+    /// The selected mirror base, ignoring stored values that are no longer configured
+    pub fn base(&self) -> Option<&'static str> {
+        let selected = self.0.as_deref()?;
+        config::download_mirrors().into_iter().find(|mirror| mirror.base == selected).map(|mirror| mirror.base)
+    }
+
+    pub fn set(&self, base: Option<&str>) {
+        match base {
+            Some(base) => self.0.set(base.to_string()),
+            None => self.0.delete(),
+        }
+    }
+}
+
+#[hook]
+pub fn use_mirror_preference() -> MirrorPreference {
+    MirrorPreference(use_local_storage("pandora.download_mirror".to_string()))
+}
+
+/// File names of the builds downloaded in this tab, kept in session storage so
+/// they are forgotten when the tab closes. Never sent anywhere
+#[derive(Clone, PartialEq)]
+pub struct RecentDownloads(pub UseSessionStorageHandle<Vec<String>>);
+
+impl RecentDownloads {
+    pub fn names(&self) -> &[String] {
+        self.0.as_deref().unwrap_or_default()
+    }
+
+    pub fn record(&self, name: &str) {
+        let mut names = self.names().to_vec();
+        names.retain(|recorded| recorded != name);
+        names.push(name.to_string());
+        self.0.set(names);
+    }
+}
+
+#[hook]
+pub fn use_recent_downloads() -> RecentDownloads {
+    RecentDownloads(use_session_storage("pandora.recent_downloads".to_string()))
+}
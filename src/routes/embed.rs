@@ -0,0 +1,70 @@
+use yew::prelude::*;
+use yew_hooks::{use_async_with_options, UseAsyncOptions};
+
+use crate::components::downloads::DownloadLink;
+use crate::config::CANONICAL_DOMAIN;
+use crate::downloads::{downloads_by_type, recommended_download};
+use crate::platform::detect_os;
+use crate::preferences::use_package_preference;
+use crate::services::github::RELEASES_PAGE;
+use crate::services::release_source::use_release_source;
+
+/// Minimal "Download Pandora" widget for other sites, always pointing at the
+/// latest release for the visitor's platform. Embed it with:
+///
+/// ```html
+/// <iframe src="https://pandora.moulberry.com/embed" title="Download Pandora"
+///     width="340" height="140" style="border: 0"></iframe>
+/// ```
+///
+/// Don't sandbox the iframe without `allow-downloads allow-popups`, or the
+/// download and the link to the full site won't work
+#[function_component(Embed)]
+pub fn embed() -> Html {
+    let source = use_release_source();
+    let release = use_async_with_options(
+        source.0.fetch_latest(),
+        UseAsyncOptions::enable_auto()
+    );
+    let preference = use_package_preference().unwrap_or_default();
+
+    let site = format!("https://{}/", CANONICAL_DOMAIN);
+    let footer = html! {
+        <p class="is-size-7 has-text-centered mt-2">
+            <a href={site} target="_blank" rel="noopener noreferrer">{"All downloads on "}{CANONICAL_DOMAIN}</a>
+        </p>
+    };
+
+    let Some(release) = &release.data else {
+        let message = if release.error.is_some() {
+            html! { <a href={RELEASES_PAGE} target="_blank" rel="noopener noreferrer">{"Download Pandora from GitHub"}</a> }
+        } else {
+            html! { {"Loading…"} }
+        };
+        return html! {
+            <div class="embed-widget">
+                <p class="has-text-centered">{message}</p>
+            </div>
+        };
+    };
+
+    let downloads = downloads_by_type(release);
+    let button = match recommended_download(detect_os(), preference) {
+        Some(download_type) => html! {
+            <DownloadLink name={download_type.recommendation_label()} download={downloads.get(&download_type).cloned()} tooltip={download_type.tradeoff()}/>
+        },
+        None => html! {
+            <a class="button is-link is-fullwidth" href={format!("https://{}/#downloads", CANONICAL_DOMAIN)} target="_blank" rel="noopener noreferrer">
+                {"Download Pandora"}
+            </a>
+        },
+    };
+
+    html! {
+        <div class="embed-widget">
+            <p class="has-text-centered has-text-white mb-2">{format!("Pandora Launcher {}", release.tag_name)}</p>
+            {button}
+            {footer}
+        </div>
+    }
+}
@@ -1,64 +1,44 @@
-use std::{collections::HashMap, sync::Arc};
+use std::rc::Rc;
 
-use serde::Deserialize;
-use ybc::{TileCtx::{Ancestor, Child, Parent}};
+use ybc::TileCtx::{Ancestor, Child, Parent};
+use yew::platform::spawn_local;
 use yew::prelude::*;
 use yew_hooks::{use_async_with_options, UseAsyncOptions};
+use yew_router::prelude::Link;
 
-#[derive(Debug, Clone, Deserialize)]
-struct GitHubReleases {
-    assets: Vec<GitHubReleaseAsset>,
-}
-
-#[derive(Debug, Clone, Deserialize)]
-struct GitHubReleaseAsset {
-    name: Arc<str>,
-    browser_download_url: Arc<str>,
-}
-
-#[derive(Properties, PartialEq)]
-struct DownloadLinkParams {
-    name: String,
-    link: Option<Arc<str>>,
-}
-
-#[function_component(DownloadLink)]
-fn download_link(params: &DownloadLinkParams) -> Html {
-    if let Some(link) = &params.link {
-        html! {
-            <a download="true" href={String::from(&**link)}>
-                <ybc::Button classes="is-fullwidth is-link">
-                    {&params.name}
-                </ybc::Button>
-            </a>
-        }
-    } else {
-        html! {
-            <ybc::Button classes="is-fullwidth is-link">
-                {&params.name}
-            </ybc::Button>
-        }
-    }
-}
+use crate::components::analytics_toggle::AnalyticsToggle;
+use crate::components::auto_refresh_toggle::AutoRefreshToggle;
+use crate::components::channels::ChannelCards;
+use crate::components::copy_button::CopyButton;
+use crate::components::debug_panel::DebugPanel;
+use crate::components::density_toggle::DensityToggle;
+use crate::components::downloads::{DownloadLink, DownloadTiles, MirrorSelect, PackageComparisonPanel, PackagePreferenceToggle, PlatformSelect};
+use crate::components::logo::Logo;
+use crate::components::notify_button::NotifyButton;
+use crate::components::open_in_launcher::OpenInLauncher;
+use crate::components::recent_downloads::RecentDownloadsNote;
+use crate::components::release_flash::ReleaseFlash;
+use crate::components::retry::Retry;
+use crate::components::share_button::ShareButton;
+use crate::components::shortcut_help::ShortcutHelp;
+use crate::components::update_banner::UpdateBanner;
+use crate::debug::debug_enabled;
+use crate::downloads::{downloads_by_type, downloads_markdown, prefer_arm64, recommended_download};
+use crate::platform::{detect_architecture, detect_macos_version, detect_os, OperatingSystem};
+use crate::routes::AppRoute;
+use crate::version::Version;
+use crate::preferences::use_package_preference;
+use crate::config::{release_poll_interval_millis, FeatureFlags, MIN_MACOS_VERSION};
+use crate::services::cache::{background_refresh_allowed, use_poll, use_refresh_when_visible};
+use crate::services::github::{newer_tag_than_cached, LATEST_RELEASE_CACHE_KEY, RELEASES_PAGE, RELEASES_TTL_MILLIS};
+use crate::services::reachability::BackendStatus;
+use crate::services::release_source::use_release_source;
+use crate::services::request::RequestError;
+use crate::services::telemetry::report_os_override;
+use crate::shortcuts::use_keyboard_shortcuts;
+use crate::util::run_when_idle;
 
-#[derive(PartialEq)]
-enum OperatingSystem {
-    Windows,
-    Linux,
-    MacOS,
-    Unknown,
-}
-
-#[derive(Hash, PartialEq, Eq, PartialOrd)]
-enum DownloadType {
-    WindowsInstaller,
-    WindowsPortable,
-    LinuxDebianInstaller,
-    LinuxAppImage,
-    LinuxPortable,
-    MacInstaller,
-    MacPortable,
-}
+const CROSTINI_SETUP_URL: &str = "https://support.google.com/chromebook/answer/9145439";
 
 #[function_component(GitHubIcon)]
 fn github_icon() -> Html {
@@ -80,70 +60,131 @@ fn youtube_icon() -> Html {
     }
 }
 
+/// Read out by screen readers as the releases move between loading, loaded and
+/// failed. Polls and visibility refreshes keep the loaded text as it is, so a
+/// background reload of data already on screen isn't announced again
+fn load_announcement(loading: bool, loaded: bool, failed: bool) -> &'static str {
+    if loaded {
+        "Downloads loaded"
+    } else if loading {
+        "Loading downloads…"
+    } else if failed {
+        "Failed to load downloads — retry available"
+    } else {
+        ""
+    }
+}
+
 #[function_component(Home)]
 pub fn home() -> Html {
+    let source = use_release_source();
     let releases = use_async_with_options(
-        async {
-            let releases: Option<GitHubReleases> = crate::services::request::get("https://api.github.com/repos/Moulberry/PandoraLauncher/releases/latest").await;
-            releases.ok_or(())
-        },
+        source.0.fetch_latest(),
         UseAsyncOptions::enable_auto()
     );
+    use_refresh_when_visible(&releases, RELEASES_TTL_MILLIS);
+    use_poll(&releases, release_poll_interval_millis());
 
-    let mut releases_by_type = HashMap::new();
-
-    if let Some(data) = &releases.data {
-        for asset in &data.assets {
-            let name = asset.name.to_ascii_lowercase();
-            let download_type = if name.ends_with(".dmg") {
-                DownloadType::MacInstaller
-            } else if name.ends_with(".appimage") {
-                DownloadType::LinuxAppImage
-            } else if name.ends_with(".deb") {
-                DownloadType::LinuxDebianInstaller
-            } else if name.ends_with("-setup.exe") {
-                DownloadType::WindowsInstaller
-            } else if name.ends_with(".exe") {
-                DownloadType::WindowsPortable
-            } else if name.contains("-macos") {
-                DownloadType::MacPortable
-            } else if name.contains("-linux") {
-                DownloadType::LinuxPortable
-            } else {
-                log::info!("Unknown download type for filename: {}", &name);
-                continue;
-            };
-
-            releases_by_type.insert(download_type, asset.browser_download_url.clone());
-        }
+    // Warm the cache for the version history once the downloads are on screen.
+    // The channel cards only ever show this cached list, so they cost no request of their own
+    let feature_flags = use_context::<FeatureFlags>().unwrap_or_else(FeatureFlags::from_env);
+    let all_releases = {
+        let source = source.clone();
+        use_state(move || source.0.cached_all().map(Rc::new))
+    };
+    {
+        let ready = releases.data.is_some();
+        let source = source.clone();
+        let all_releases = all_releases.clone();
+        use_effect_with_deps(move |&ready| {
+            if ready && all_releases.is_none() && feature_flags.prefetch_releases && background_refresh_allowed() {
+                run_when_idle(move || spawn_local(async move {
+                    match source.0.fetch_all().await {
+                        Ok(list) => all_releases.set(Some(Rc::new(list))),
+                        Err(error) => log::debug!("Prefetching the release list failed: {}", error),
+                    }
+                }));
+            }
+        }, ready);
     }
 
-    let operating_system = if let Ok(user_agent) = web_sys::window().unwrap().navigator().user_agent() {
-        if user_agent.contains("Mac") {
-            OperatingSystem::MacOS
-        } else if user_agent.contains("Win") {
-            OperatingSystem::Windows
-        } else if user_agent.contains("Linux") {
-            OperatingSystem::Linux
-        } else {
-            OperatingSystem::Unknown
+    let on_refresh = {
+        let releases = releases.clone();
+        Callback::from(move |_| releases.run())
+    };
+
+    let backend_status = use_context::<BackendStatus>().unwrap_or_default();
+    // The jsDelivr stand-in only knows the tag, so it can't list downloads
+    let show_offline_fallback = releases.data.as_ref().map_or(backend_status.is_unreachable(), |release| release.from_fallback);
+    // The cached release is still served when jsDelivr says there is a newer one GitHub can't give us
+    let newer_tag = releases.data.as_ref().and_then(|release| Some((newer_tag_than_cached()?, release.tag_name.clone())));
+    let behind_captive_portal = releases.data.is_none()
+        && matches!(releases.error, Some(RequestError::UnexpectedContentType(_)));
+
+    let load_announcement = load_announcement(releases.loading, releases.data.is_some(), releases.error.is_some());
+
+    let releases_by_type = Rc::new(releases.data.as_ref().map(downloads_by_type).unwrap_or_default());
+
+    let detected_os = detect_os();
+    let os_override = use_state(|| None);
+    let operating_system = os_override.unwrap_or(detected_os);
+    let on_os_change = Callback::from(move |chosen| {
+        if chosen != detected_os {
+            report_os_override(feature_flags, detected_os, chosen);
         }
-    } else {
-        OperatingSystem::Unknown
+        os_override.set(Some(chosen));
+    });
+
+    // Only judged for the visitor's own Mac, not when they picked macOS for another machine
+    let unsupported_macos = (operating_system == OperatingSystem::MacOS && detected_os == OperatingSystem::MacOS)
+        .then(detect_macos_version)
+        .flatten()
+        .zip(MIN_MACOS_VERSION.and_then(|minimum| minimum.parse::<Version>().ok()))
+        .filter(|(version, minimum)| version < minimum);
+
+    let arm64_detected = use_state(|| false);
+    {
+        let arm64_detected = arm64_detected.clone();
+        use_effect_with_deps(move |_| {
+            spawn_local(async move {
+                arm64_detected.set(detect_architecture().await.is_some_and(|detected| detected.is_arm64()));
+            });
+        }, ());
+    }
+    // Like the macOS check, only for the visitor's own machine, and only once the release has an ARM64 build
+    let arm64 = prefer_arm64(*arm64_detected && operating_system == detected_os, &releases_by_type);
+
+    let package_preference = use_package_preference();
+    let preference = package_preference.unwrap_or_default();
+    let on_preference_change = Callback::from(move |preference| package_preference.set(preference));
+    let recommended = recommended_download(operating_system, preference).map(|download_type| download_type.for_architecture(arm64));
+
+    let help_open = use_keyboard_shortcuts();
+    let on_help_open = {
+        let help_open = help_open.clone();
+        Callback::from(move |_| help_open.set(true))
+    };
+    let on_help_close = {
+        let help_open = help_open.clone();
+        Callback::from(move |_| help_open.set(false))
     };
 
     html! {
         <>
 
+        <a class="skip-link" href="#downloads">{"Skip to downloads"}</a>
+        <div class="is-sr-only" role="status" aria-live="polite">{load_announcement}</div>
+
         <ybc::Hero
             classes="is-dark"
             size={ybc::HeroSize::FullheightWithNavbar}
             body={html!{
                 <ybc::Container classes="is-centered">
-                <ybc::Section>
+                <div class="hero-layout">
+                <ybc::Section classes="hero-intro">
                 <ybc::Container classes="has-text-centered">
                     <span class={classes!("hero-banner-text")}>
-                        <img src="pandora_main.svg" style="width: 45%"/>
+                        <Logo classes="hero-logo"/>
                     </span>
                     <ybc::Subtitle size={ybc::HeaderSize::Is3}>
                         {"Pandora is a modern Minecraft launcher that balances ease-of-use with powerful instance management features "}
@@ -156,33 +197,86 @@ pub fn home() -> Html {
                             <YouTubeIcon />
                         </a>
                     </div>
+                    <ShareButton/>
                 </ybc::Container>
                 </ybc::Section>
 
-                <div style="display: flex; flex-direction: column; align-items: center;">
+                <div class="hero-cta">
+                <UpdateBanner latest={releases.data.clone()}/>
+                <ReleaseFlash tag={releases.data.as_ref().map(|release| release.tag_name.clone())}/>
+                if behind_captive_portal {
+                    <ybc::Notification classes="is-warning">
+                        {"Unexpected response from GitHub — are you behind a captive portal? "}
+                        <a href={RELEASES_PAGE} target="_blank" rel="noopener noreferrer">{"Try the GitHub releases page"}</a>
+                    </ybc::Notification>
+                }
+                if show_offline_fallback {
+                    <ybc::Notification classes="is-warning">
+                        {"GitHub couldn't be reached, so downloads can't be listed here. "}
+                        <a href={RELEASES_PAGE} target="_blank" rel="noopener noreferrer">{"Download from the GitHub releases page"}</a>
+                    </ybc::Notification>
+                }
+                if let Some(error) = releases.error.as_ref().filter(|_| releases.data.is_none() && !behind_captive_portal && !show_offline_fallback) {
+                    <ybc::Notification classes="is-danger">
+                        {format!("Couldn't load the latest release: {}. ", error)}
+                        <a href={RELEASES_PAGE} target="_blank" rel="noopener noreferrer">{"Download from the GitHub releases page"}</a>
+                        <Retry onretry={on_refresh.clone()} cache_key={LATEST_RELEASE_CACHE_KEY}/>
+                    </ybc::Notification>
+                }
+                if let Some((newer, cached)) = &newer_tag {
+                    <ybc::Notification classes="is-warning">
+                        {format!("Pandora {} is out, but GitHub couldn't be reached to list its downloads, so these are for {}. ", newer, cached)}
+                        <a href={RELEASES_PAGE} target="_blank" rel="noopener noreferrer">{format!("Get {} from the GitHub releases page", newer)}</a>
+                    </ybc::Notification>
+                }
                 {{
-                    if operating_system == OperatingSystem::Windows {
-                        html! {
-                            <div class="column is-one-third">
-                                <DownloadLink name="Download Windows Installer (.exe)" link={releases_by_type.get(&DownloadType::WindowsInstaller).cloned()}/>
-                            </div>
-                        }
-                    } else if operating_system == OperatingSystem::MacOS {
+                    if let Some(download_type) = recommended {
                         html! {
                             <div class="column is-one-third">
-                                <DownloadLink name="Download macOS Installer (.dmg)" link={releases_by_type.get(&DownloadType::MacInstaller).cloned()}/>
+                                if releases.data.as_ref().is_some_and(|release| release.prerelease) {
+                                    <p class="has-text-centered mb-2"><span class="tag is-warning">{"Pre-release"}</span></p>
+                                }
+                                <DownloadLink name={download_type.recommendation_label()} download={releases_by_type.get(&download_type).cloned()} tooltip={download_type.tradeoff()} show_size=true/>
+                                if let Some(release) = &releases.data {
+                                    <OpenInLauncher tag={release.tag_name.clone()} fallback={releases_by_type.get(&download_type).cloned()}/>
+                                }
+                                <p class="is-size-7 has-text-centered mt-2">
+                                    <a href="#downloads" class="has-text-grey-light">{"Need it for a different OS? See all downloads ↓"}</a>
+                                </p>
                             </div>
                         }
                     } else {
                         Default::default()
                     }
                 }}
+                if let Some((version, minimum)) = &unsupported_macos {
+                    <ybc::Notification classes="is-warning">
+                        {format!(
+                            "Your Mac appears to run macOS {}.{}, but this build needs {}.{} or newer and may not start. ",
+                            version.major, version.minor, minimum.major, minimum.minor
+                        )}
+                        <Link<AppRoute> to={AppRoute::Versions}>{"Find an older compatible version"}</Link<AppRoute>>
+                    </ybc::Notification>
+                }
+                if operating_system == OperatingSystem::ChromeOS {
+                    <p class="is-size-7 has-text-centered">
+                        {"On ChromeOS, enable the Linux (Crostini) environment first. "}
+                        <a href={CROSTINI_SETUP_URL} target="_blank" rel="noopener noreferrer">{"How to set up Linux"}</a>
+                    </p>
+                }
                 <div class="column is-one-third">
                     <a href="#downloads">
                         <ybc::Button classes="is-fullwidth">{"View downloads"}</ybc::Button>
                     </a>
                 </div>
+                <div class="level is-mobile">
+                    <span class="level-item mr-2">{"Your platform:"}</span>
+                    <PlatformSelect selected={operating_system} onchange={on_os_change}/>
                 </div>
+                </div>
+                </div>
+
+                <ChannelCards releases={(*all_releases).clone()} {operating_system} {preference}/>
 
                 <ybc::Section>
                 <ybc::Tile classes="is-vertical" ctx={Ancestor}>
@@ -249,53 +343,148 @@ pub fn home() -> Html {
                             </ybc::Tile>
                         </ybc::Tile>
                     </ybc::Tile>
-                    <div id="downloads" style="display: flex; flex-direction: column; align-items: center; padding-top: 40px;">
+                    <div id="downloads" tabindex="-1" style="display: flex; flex-direction: column; align-items: center; padding-top: 40px;">
                     <ybc::Subtitle size={ybc::HeaderSize::Is2} classes="has-text-white">
                         {"Downloads"}
                     </ybc::Subtitle>
+                    if let Some(release) = releases.data.as_ref().filter(|release| release.prerelease) {
+                        <span class="tag is-warning mb-3" title="No stable release has been published yet">
+                            {format!("Pre-release {}", release.tag_name)}
+                        </span>
+                    }
+                    <PackagePreferenceToggle {preference} onchange={on_preference_change}/>
+                    <PackageComparisonPanel/>
+                    <MirrorSelect/>
                     </div>
-                    <ybc::Tile>
-                        <ybc::Tile ctx={Parent} size={ybc::TileSize::Four}>
-                            <ybc::Tile ctx={Child} classes="notification is-primary">
-                                <ybc::Subtitle size={ybc::HeaderSize::Is3} classes="has-text-white">
-                                    {"Windows x64"}
-                                </ybc::Subtitle>
-                                <div style="display: flex; flex-direction: column; gap: 10px">
-                                <DownloadLink name="Installer .exe" link={releases_by_type.get(&DownloadType::WindowsInstaller).cloned()}/>
-                                <DownloadLink name="Portable Executable .exe" link={releases_by_type.get(&DownloadType::WindowsPortable).cloned()}/>
-                                </div>
-                            </ybc::Tile>
-                        </ybc::Tile>
-                        <ybc::Tile ctx={Parent} size={ybc::TileSize::Four}>
-                            <ybc::Tile ctx={Child} classes="notification is-primary">
-                                <ybc::Subtitle size={ybc::HeaderSize::Is3} classes="has-text-white">
-                                    {"Linux x64"}
-                                </ybc::Subtitle>
-                                <div style="display: flex; flex-direction: column; gap: 10px">
-                                <DownloadLink name="Debian Installer .deb" link={releases_by_type.get(&DownloadType::LinuxDebianInstaller).cloned()}/>
-                                <DownloadLink name="AppImage .AppImage" link={releases_by_type.get(&DownloadType::LinuxAppImage).cloned()}/>
-                                <DownloadLink name="Portable Executable" link={releases_by_type.get(&DownloadType::LinuxPortable).cloned()}/>
-                                </div>
-                            </ybc::Tile>
-                        </ybc::Tile>
-                        <ybc::Tile ctx={Parent} size={ybc::TileSize::Four}>
-                            <ybc::Tile ctx={Child} classes="notification is-primary">
-                                <ybc::Subtitle size={ybc::HeaderSize::Is3} classes="has-text-white">
-                                    {"macOS"}
-                                </ybc::Subtitle>
-                                <div style="display: flex; flex-direction: column; gap: 10px">
-                                <DownloadLink name="Installer .dmg" link={releases_by_type.get(&DownloadType::MacInstaller).cloned()}/>
-                                <DownloadLink name="Portable Executable" link={releases_by_type.get(&DownloadType::MacPortable).cloned()}/>
-                                </div>
-                            </ybc::Tile>
-                        </ybc::Tile>
-                    </ybc::Tile>
+                    <DownloadTiles downloads={releases_by_type.clone()} {preference} primary={recommended} {operating_system} {arm64}/>
+                    <RecentDownloadsNote/>
+                    if !releases_by_type.is_empty() {
+                        <div class="buttons is-centered">
+                            <CopyButton text={downloads_markdown(&releases_by_type)} label="Copy as Markdown" title="Copy a Markdown table of these downloads" classes="is-dark"/>
+                        </div>
+                    }
+                    <AnalyticsToggle/>
+                    <NotifyButton/>
+                    <AutoRefreshToggle onrefresh={on_refresh} loading={releases.loading}/>
+                    <DensityToggle/>
+                    if debug_enabled() {
+                        <DebugPanel release={releases.data.clone()} downloads={releases_by_type.clone()} {detected_os}/>
+                    }
                 </ybc::Tile>
                 </ybc::Section>
                 </ybc::Container>
             }}>
         </ybc::Hero>
 
+        <button class="button is-small shortcut-help-button" aria-label="Keyboard shortcuts" title="Keyboard shortcuts (?)" onclick={on_help_open}>
+            {"?"}
+        </button>
+        <ShortcutHelp open={*help_open} onclose={on_help_close}/>
+
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn background_refreshes_are_not_announced() {
+        assert_eq!(load_announcement(true, false, false), "Loading downloads…");
+        assert_eq!(load_announcement(false, true, false), "Downloads loaded");
+        // A poll or visibility refresh with the release still on screen
+        assert_eq!(load_announcement(true, true, false), "Downloads loaded");
+        assert_eq!(load_announcement(false, false, true), "Failed to load downloads — retry available");
+        assert_eq!(load_announcement(true, false, true), "Loading downloads…");
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use gloo::storage::{LocalStorage, Storage};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+    use web_sys::HtmlElement;
+    use yew_router::Routable;
+
+    use super::*;
+    use crate::services::release_source::StubSource;
+    use crate::fixtures::{download_url, release_with};
+    use crate::testing::render;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    const ASSETS: &[&str] = &[
+        "Pandora-1.2.3-setup.exe",
+        "Pandora-1.2.3.dmg",
+        "pandora_1.2.3_amd64.deb",
+        "Pandora-1.2.3-x86_64.AppImage",
+    ];
+
+    fn tile_links(page: &crate::testing::Rendered) -> Vec<String> {
+        page.select_all(".download-tiles a[download]").iter()
+            .filter_map(|link| link.get_attribute("href"))
+            .collect()
+    }
+
+    #[wasm_bindgen_test]
+    async fn download_tiles_link_to_the_release_assets() {
+        LocalStorage::delete("pandora.advanced_downloads_open");
+        let source = StubSource::with_latest(release_with("1.2.3", ASSETS)).into_handle();
+        let page = render(source, html! { <Home/> });
+        assert!(page.wait_for(".download-tiles a[download]").await.is_some(), "no download links rendered");
+
+        let titles: Vec<_> = page.select_all(".download-tiles .subtitle").iter()
+            .filter_map(|title| title.text_content())
+            .collect();
+        for platform in ["Windows x64", "Linux", "macOS"] {
+            assert!(titles.iter().any(|title| title == platform), "no tile for {}", platform);
+        }
+
+        // Only each platform's preferred build until advanced downloads are opened
+        let links = tile_links(&page);
+        for name in ["Pandora-1.2.3-setup.exe", "Pandora-1.2.3.dmg", "pandora_1.2.3_amd64.deb"] {
+            assert!(links.contains(&download_url(name)), "no link to {} in {:?}", name, links);
+        }
+        assert!(!links.contains(&download_url("Pandora-1.2.3-x86_64.AppImage")));
+
+        let advanced = page.select_all("button[aria-expanded]").into_iter()
+            .find(|button| button.text_content().unwrap_or_default().starts_with("Advanced downloads"))
+            .expect("no advanced downloads button");
+        advanced.unchecked_into::<HtmlElement>().click();
+        page.settle().await;
+        assert!(tile_links(&page).contains(&download_url("Pandora-1.2.3-x86_64.AppImage")));
+    }
+
+    #[wasm_bindgen_test]
+    async fn missing_builds_point_to_older_versions() {
+        LocalStorage::delete("pandora.advanced_downloads_open");
+        let source = StubSource::with_latest(release_with("1.2.3", &["Pandora-1.2.3-setup.exe"])).into_handle();
+        let page = render(source, html! { <Home/> });
+        assert!(page.wait_for(".download-tiles a[download]").await.is_some(), "no download links rendered");
+
+        assert_eq!(tile_links(&page), vec![download_url("Pandora-1.2.3-setup.exe")]);
+        let older = page.select_all(&format!(".download-tiles a[href='{}']", AppRoute::Versions.to_path()));
+        assert_eq!(older.len(), 2, "Linux and macOS should each link to older versions");
+    }
+
+    #[wasm_bindgen_test]
+    async fn a_release_without_assets_is_not_an_outage() {
+        let source = StubSource::with_latest(release_with("1.2.3", &[])).into_handle();
+        let page = render(source, html! { <Home/> });
+        assert!(page.wait_for(".download-tiles").await.is_some(), "no download tiles rendered");
+        page.settle().await;
+        assert!(!page.text().contains("GitHub couldn't be reached"), "{}", page.text());
+    }
+
+    #[wasm_bindgen_test]
+    async fn the_jsdelivr_stand_in_shows_the_outage_notice() {
+        let mut release = release_with("1.2.3", &[]);
+        release.from_fallback = true;
+        let page = render(StubSource::with_latest(release).into_handle(), html! { <Home/> });
+        assert!(page.wait_for(".download-tiles").await.is_some(), "no download tiles rendered");
+        page.settle().await;
+        assert!(page.text().contains("GitHub couldn't be reached"), "{}", page.text());
+    }
+}
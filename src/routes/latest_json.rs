@@ -0,0 +1,32 @@
+use yew::prelude::*;
+use yew_hooks::{use_async_with_options, UseAsyncOptions};
+
+use crate::downloads::LatestSummary;
+use crate::services::release_source::use_release_source;
+
+/// `{version, downloads}` for the latest release, rendered as a JSON blob for
+/// people to read or copy. It is not machine-consumable: this is a
+/// client-rendered route, so fetching the URL returns the app's HTML (GitHub
+/// Pages' 404.html fallback, with a 404 status). Scripts
+/// should query https://api.github.com/repos/Moulberry/PandoraLauncher/releases/latest
+/// directly instead
+#[function_component(LatestJson)]
+pub fn latest_json() -> Html {
+    let source = use_release_source();
+    let release = use_async_with_options(
+        source.0.fetch_latest(),
+        UseAsyncOptions::enable_auto()
+    );
+
+    let json = if let Some(release) = &release.data {
+        serde_json::to_string_pretty(&LatestSummary::from_release(release)).unwrap_or_default()
+    } else if let Some(error) = &release.error {
+        serde_json::json!({ "error": error.to_string() }).to_string()
+    } else {
+        return Default::default();
+    };
+
+    html! {
+        <pre class="latest-json">{json}</pre>
+    }
+}
@@ -1,21 +1,89 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::config::FeatureFlags;
+use crate::services::telemetry::report_pageview;
+
+mod embed;
 mod home;
+mod latest_json;
+mod not_found;
+mod release;
+mod stats;
+mod versions;
 
-/// App routes
+/// App routes. Each one is listed in sitemap.xml by scripts/sitemap.sh unless
+/// it takes parameters or is marked "not in sitemap"
 #[derive(Routable, Debug, Clone, PartialEq, Eq)]
 pub enum AppRoute {
     #[at("/")]
     Home,
+    #[at("/releases")]
+    Versions,
+    #[at("/release/:tag")]
+    Release { tag: String },
+    #[at("/stats")]
+    Stats,
+    // not in sitemap: JSON for people to read, fetching it returns the app's 404.html
+    #[at("/api/latest.json")]
+    LatestJson,
+    // not in sitemap: only meant for iframes
+    #[at("/embed")]
+    Embed,
+    // not in sitemap
     #[not_found]
     #[at("/404")]
     NotFound,
 }
 
+impl AppRoute {
+    /// Stable name used when reporting page views, without any route parameters
+    pub fn name(&self) -> &'static str {
+        match self {
+            AppRoute::Home => "home",
+            AppRoute::Versions => "versions",
+            AppRoute::Release { .. } => "release",
+            AppRoute::Stats => "stats",
+            AppRoute::LatestJson => "latest_json",
+            AppRoute::Embed => "embed",
+            AppRoute::NotFound => "not_found",
+        }
+    }
+
+    /// Route to a release, with the tag percent-encoded since `to_path` inserts it verbatim
+    pub fn release(tag: &str) -> Self {
+        AppRoute::Release { tag: String::from(js_sys::encode_uri_component(tag)) }
+    }
+}
+
+/// Absolute, shareable URL of a release page
+pub fn release_url(tag: &str) -> String {
+    let origin = web_sys::window()
+        .and_then(|window| window.location().origin().ok())
+        .unwrap_or_default();
+    format!("{}{}", origin, AppRoute::release(tag).to_path())
+}
+
+/// Reports a page view each time the route changes
+#[function_component(PageviewCounter)]
+pub fn pageview_counter() -> Html {
+    let route = use_route::<AppRoute>().unwrap_or(AppRoute::NotFound);
+    let feature_flags = use_context::<FeatureFlags>().unwrap_or_else(FeatureFlags::from_env);
+    use_effect_with_deps(move |name| report_pageview(feature_flags, name), route.name());
+    Default::default()
+}
+
 pub fn switch(route: AppRoute) -> Html {
     match route {
         AppRoute::Home => html! {<home::Home />},
-        AppRoute::NotFound => html! { "Page not found" },
+        AppRoute::Versions => html! {<versions::Versions />},
+        AppRoute::Release { tag } => {
+            let tag = js_sys::decode_uri_component(&tag).map(String::from).unwrap_or(tag);
+            html! {<release::Release key={tag.as_str()} tag={tag.clone()} />}
+        },
+        AppRoute::Stats => html! {<stats::Stats />},
+        AppRoute::LatestJson => html! {<latest_json::LatestJson />},
+        AppRoute::Embed => html! {<embed::Embed />},
+        AppRoute::NotFound => html! {<not_found::NotFound />},
     }
 }
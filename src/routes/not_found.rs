@@ -0,0 +1,61 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::routes::AppRoute;
+
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Closest route without parameters to `path`, if it is within a couple of typos
+fn suggest_route(path: &str) -> Option<AppRoute> {
+    let path = path.to_lowercase();
+    let path = path.trim_end_matches('/');
+    AppRoute::routes()
+        .into_iter()
+        .filter(|route| !route.contains([':', '*']) && *route != "/404")
+        .map(|route| (levenshtein(path, route), route))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .and_then(|(_, route)| AppRoute::recognize(route))
+}
+
+#[function_component(NotFound)]
+pub fn not_found() -> Html {
+    let location = use_location();
+    let suggestion = location.as_ref().and_then(|location| suggest_route(location.path()));
+
+    html! {
+        <ybc::Hero
+            classes="is-dark"
+            size={ybc::HeroSize::FullheightWithNavbar}
+            body={html!{
+                <ybc::Container classes="has-text-centered">
+                    <ybc::Title size={ybc::HeaderSize::Is2} classes="has-text-white">
+                        {"Page not found"}
+                    </ybc::Title>
+                    if let Some(route) = suggestion {
+                        <p class="mb-4">
+                            {"Did you mean "}
+                            <Link<AppRoute> to={route.clone()}>{route.to_path()}</Link<AppRoute>>
+                            {"?"}
+                        </p>
+                    }
+                    <Link<AppRoute> to={AppRoute::Home}>{"Back to the home page"}</Link<AppRoute>>
+                </ybc::Container>
+            }}>
+        </ybc::Hero>
+    }
+}
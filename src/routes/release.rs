@@ -0,0 +1,248 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_hooks::{use_async_with_options, UseAsyncOptions};
+use yew_router::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::components::logo::HomeLogoLink;
+use crate::components::downloads::{DownloadLink, DownloadTiles};
+use crate::components::release_notes::ReleaseNotes;
+use crate::components::retry::Retry;
+use crate::downloads::{downloads_by_type, Download};
+use crate::preferences::use_package_preference;
+use crate::routes::AppRoute;
+use crate::services::github::{GitHubReleaseAsset, GitHubReleases};
+use crate::services::release_source::use_release_source;
+use crate::util::format_bytes;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AssetChange {
+    New,
+    Updated,
+    Unchanged,
+}
+
+/// Asset name with the release's version removed, so the same artifact can be
+/// matched across releases
+fn asset_key(name: &str, tag: &str) -> String {
+    let version = tag.trim_start_matches(['v', 'V']);
+    if version.is_empty() {
+        name.to_string()
+    } else {
+        name.replace(tag, "{version}").replace(version, "{version}")
+    }
+}
+
+/// Newest release older than `release`, if any
+fn predecessor<'a>(release: &GitHubReleases, releases: &'a [GitHubReleases]) -> Option<&'a GitHubReleases> {
+    let version = release.version()?;
+    releases.iter()
+        .filter_map(|other| Some((other.version()?, other)))
+        .filter(|(other_version, _)| *other_version < version)
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, other)| other)
+}
+
+fn asset_change(asset: &GitHubReleaseAsset, tag: &str, previous: &GitHubReleases) -> AssetChange {
+    let key = asset_key(&asset.name, tag);
+    let previous_asset = previous.assets.iter()
+        .find(|previous_asset| asset_key(&previous_asset.name, &previous.tag_name) == key);
+    match previous_asset {
+        None => AssetChange::New,
+        Some(previous_asset) if previous_asset.size != asset.size => AssetChange::Updated,
+        Some(_) => AssetChange::Unchanged,
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct AssetChangesParams {
+    release: Rc<GitHubReleases>,
+    previous: Rc<GitHubReleases>,
+}
+
+/// Lists this release's assets, badging the ones that are new or changed size
+/// since the previous release
+#[function_component(AssetChanges)]
+fn asset_changes(params: &AssetChangesParams) -> Html {
+    let release = &params.release;
+
+    html! {
+        <div class="asset-changes">
+            <p class="heading has-text-centered">{format!("Compared to {}", params.previous.tag_name)}</p>
+            <ul>
+                { for release.assets.iter().filter(|asset| asset.is_uploaded()).map(|asset| {
+                    let badge = match asset_change(asset, &release.tag_name, &params.previous) {
+                        AssetChange::New => html! { <span class="tag is-success ml-2">{"new"}</span> },
+                        AssetChange::Updated => html! { <span class="tag is-info ml-2">{"updated"}</span> },
+                        AssetChange::Unchanged => Default::default(),
+                    };
+                    html! { <li><span class="asset-name" title={asset.name.to_string()}>{&*asset.name}</span>{badge}</li> }
+                }) }
+            </ul>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct FilteredAssetsParams {
+    assets: Rc<Vec<GitHubReleaseAsset>>,
+    filter: String,
+}
+
+#[function_component(FilteredAssets)]
+fn filtered_assets(params: &FilteredAssetsParams) -> Html {
+    let filter = params.filter.to_lowercase();
+    let matching: Vec<_> = params.assets.iter()
+        .filter(|asset| asset.is_uploaded() && asset.name.to_lowercase().contains(&filter))
+        .collect();
+
+    if matching.is_empty() {
+        return html! { <p class="has-text-centered">{"No assets match that filter"}</p> };
+    }
+
+    html! {
+        <div class="download-buttons">
+            { for matching.into_iter().map(|asset| html! {
+                <DownloadLink name={asset.name.to_string()} download={Download::from_asset(asset)}/>
+            }) }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ReleaseParams {
+    pub tag: String,
+}
+
+#[function_component(Release)]
+pub fn release(params: &ReleaseParams) -> Html {
+    let release = {
+        let tag = params.tag.clone();
+        use_async_with_options(
+            async move { crate::services::github::fetch_release_by_tag(&tag).await },
+            UseAsyncOptions::enable_auto()
+        )
+    };
+
+    let source = use_release_source();
+    let all_releases = use_async_with_options(
+        source.0.fetch_all(),
+        UseAsyncOptions::enable_auto()
+    );
+
+    let preference = use_package_preference().unwrap_or_default();
+    let filter = use_state(String::new);
+
+    let on_filter_input = {
+        let filter = filter.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: web_sys::HtmlInputElement = event.target_unchecked_into();
+            filter.set(input.value());
+        })
+    };
+    let on_filter_clear = {
+        let filter = filter.clone();
+        Callback::from(move |_| filter.set(String::new()))
+    };
+
+    let content = if let Some(data) = &release.data {
+        let downloads = Rc::new(downloads_by_type(data));
+        let total_size: u64 = data.assets.iter().map(|asset| asset.size).sum();
+        let previous = all_releases.data.as_deref().and_then(|releases| predecessor(data, releases));
+
+        html! {
+            <>
+            <ybc::Title size={ybc::HeaderSize::Is2} classes="has-text-white has-text-centered">
+                {data.display_name()}
+                if !data.has_title() {
+                    <CopyButton text={data.tag_name.to_string()} classes="ml-3 is-dark copy-version"/>
+                }
+            </ybc::Title>
+            if data.has_title() {
+                <p class="has-text-centered mb-4">
+                    <code>{&*data.tag_name}</code>
+                    <CopyButton text={data.tag_name.to_string()} classes="ml-2 is-dark copy-version"/>
+                </p>
+            }
+            <ybc::Subtitle size={ybc::HeaderSize::Is5} classes="has-text-centered">
+                {format!(
+                    "Full release: {} across {} {}",
+                    format_bytes(total_size),
+                    data.assets.len(),
+                    if data.assets.len() == 1 { "file" } else { "files" }
+                )}
+            </ybc::Subtitle>
+            {{
+                if let Some(body) = data.body.as_deref().filter(|body| !body.trim().is_empty()) {
+                    html! {
+                        <ybc::Tile ctx={ybc::TileCtx::Child} classes="notification is-primary">
+                            <ReleaseNotes body={body.to_string()}/>
+                        </ybc::Tile>
+                    }
+                } else {
+                    Default::default()
+                }
+            }}
+            <div class="field has-addons asset-filter">
+                <div class="control is-expanded">
+                    <input class="input" type="search" placeholder="Filter assets, e.g. arm64" aria-label="Filter assets"
+                        value={(*filter).clone()} oninput={on_filter_input}/>
+                </div>
+                <div class="control">
+                    <button class="button" onclick={on_filter_clear} disabled={filter.is_empty()}>{"Clear"}</button>
+                </div>
+            </div>
+            if filter.trim().is_empty() {
+                <ybc::Tile classes="is-vertical" ctx={ybc::TileCtx::Ancestor}>
+                    <DownloadTiles {downloads} {preference}/>
+                </ybc::Tile>
+            } else {
+                <FilteredAssets assets={Rc::new(data.assets.clone())} filter={filter.trim().to_string()}/>
+            }
+            if let Some(previous) = previous {
+                <AssetChanges release={Rc::new(data.clone())} previous={Rc::new(previous.clone())}/>
+            }
+            </>
+        }
+    } else if let Some(error) = &release.error {
+        let on_retry = {
+            let release = release.clone();
+            Callback::from(move |_| release.run())
+        };
+        let message = if error.is_not_found() {
+            format!("Release {} not found", params.tag)
+        } else {
+            format!("Failed to load release {}", params.tag)
+        };
+
+        html! {
+            <div class="has-text-centered">
+                <ybc::Title size={ybc::HeaderSize::Is2} classes="has-text-white">
+                    {message}
+                </ybc::Title>
+                if !error.is_not_found() {
+                    <Retry onretry={on_retry}/>
+                }
+                <Link<AppRoute> to={AppRoute::Home}>{"Back to the latest release"}</Link<AppRoute>>
+            </div>
+        }
+    } else {
+        Default::default()
+    };
+
+    html! {
+        <ybc::Hero
+            classes="is-dark"
+            size={ybc::HeroSize::FullheightWithNavbar}
+            body={html!{
+                <ybc::Container>
+                <ybc::Section>
+                    <HomeLogoLink/>
+                    {content}
+                </ybc::Section>
+                </ybc::Container>
+            }}>
+        </ybc::Hero>
+    }
+}
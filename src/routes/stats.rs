@@ -0,0 +1,201 @@
+use yew::prelude::*;
+use yew_hooks::{use_async_with_options, UseAsyncOptions};
+
+use crate::components::logo::HomeLogoLink;
+use crate::components::retry::Retry;
+use crate::downloads::classify_asset;
+use crate::platform::OperatingSystem;
+use crate::services::cache::use_refresh_when_visible;
+use crate::services::github::{GitHubReleases, ALL_RELEASES_CACHE_KEY, RELEASES_TTL_MILLIS};
+use crate::services::release_source::use_release_source;
+use crate::util::{format_date, format_number};
+
+struct ProjectStats {
+    release_count: usize,
+    first_release: Option<chrono::DateTime<chrono::Utc>>,
+    total_downloads: u64,
+}
+
+fn project_stats(releases: &[GitHubReleases]) -> ProjectStats {
+    ProjectStats {
+        release_count: releases.len(),
+        first_release: releases.iter().filter_map(|release| release.published_at).min(),
+        total_downloads: releases.iter()
+            .flat_map(|release| &release.assets)
+            .map(|asset| asset.download_count)
+            .sum(),
+    }
+}
+
+/// Platforms that have builds of their own
+const CHART_PLATFORMS: [OperatingSystem; 3] = [OperatingSystem::Windows, OperatingSystem::Linux, OperatingSystem::MacOS];
+
+/// Download counts summed per OS across every classified asset of every release
+fn downloads_by_os(releases: &[GitHubReleases]) -> Vec<(OperatingSystem, u64)> {
+    CHART_PLATFORMS.iter().copied()
+        .map(|os| {
+            let total = releases.iter()
+                .flat_map(|release| &release.assets)
+                .filter(|asset| classify_asset(&asset.name).map(|download_type| download_type.os()) == Some(os))
+                .map(|asset| asset.download_count)
+                .sum();
+            (os, total)
+        })
+        .collect()
+}
+
+const CHART_WIDTH: u64 = 600;
+const CHART_LABEL_WIDTH: u64 = 90;
+const CHART_BAR_HEIGHT: u64 = 32;
+const CHART_BAR_GAP: u64 = 12;
+
+#[derive(Properties, PartialEq)]
+struct PlatformChartParams {
+    totals: Vec<(OperatingSystem, u64)>,
+}
+
+/// Horizontal bar chart of downloads per platform, drawn as a plain SVG
+#[function_component(PlatformChart)]
+fn platform_chart(params: &PlatformChartParams) -> Html {
+    let max = params.totals.iter().map(|(_, total)| *total).max().unwrap_or(0).max(1);
+    let bar_space = CHART_WIDTH - CHART_LABEL_WIDTH - 80;
+    let height = params.totals.len() as u64 * (CHART_BAR_HEIGHT + CHART_BAR_GAP);
+
+    html! {
+        <svg class="platform-chart" viewBox={format!("0 0 {} {}", CHART_WIDTH, height)} role="img" aria-label="Downloads by platform">
+            { for params.totals.iter().enumerate().map(|(index, (os, total))| {
+                let y = index as u64 * (CHART_BAR_HEIGHT + CHART_BAR_GAP);
+                let width = bar_space * total / max;
+                let text_y = y + CHART_BAR_HEIGHT / 2;
+                html! {
+                    <g>
+                        <text x="0" y={text_y.to_string()} dominant-baseline="middle" fill="currentColor">{os.name()}</text>
+                        <rect x={CHART_LABEL_WIDTH.to_string()} y={y.to_string()} width={width.to_string()} height={CHART_BAR_HEIGHT.to_string()} rx="4"/>
+                        <text x={(CHART_LABEL_WIDTH + width + 8).to_string()} y={text_y.to_string()} dominant-baseline="middle" fill="currentColor">{format_number(*total)}</text>
+                    </g>
+                }
+            }) }
+        </svg>
+    }
+}
+
+#[function_component(Stats)]
+pub fn stats() -> Html {
+    let source = use_release_source();
+    let releases = use_async_with_options(
+        source.0.fetch_all(),
+        UseAsyncOptions::enable_auto()
+    );
+    use_refresh_when_visible(&releases, RELEASES_TTL_MILLIS);
+    // Left out of the strip if it fails, the release stats don't depend on it
+    let repository = use_async_with_options(
+        source.0.fetch_repository(),
+        UseAsyncOptions::enable_auto()
+    );
+
+    let content = if releases.data.as_ref().is_some_and(Vec::is_empty) {
+        html! { <p class="has-text-centered">{"No releases published yet — check back soon"}</p> }
+    } else if let Some(releases) = &releases.data {
+        let stats = project_stats(releases);
+        let since = stats.first_release
+            .map(|date| format!(" since {}", format_date(date)))
+            .unwrap_or_default();
+
+        html! {
+            <>
+            <ybc::Level>
+                <ybc::LevelItem classes="has-text-centered">
+                    <div>
+                        <p class="heading">{"Releases"}</p>
+                        <p class="title has-text-white">{format!("{} releases{}", stats.release_count, since)}</p>
+                    </div>
+                </ybc::LevelItem>
+                <ybc::LevelItem classes="has-text-centered">
+                    <div>
+                        <p class="heading">{"Downloads"}</p>
+                        <p class="title has-text-white">{format_number(stats.total_downloads)}</p>
+                    </div>
+                </ybc::LevelItem>
+                if let Some(repository) = &repository.data {
+                    <ybc::LevelItem classes="has-text-centered">
+                        <div>
+                            <p class="heading">{"Stars"}</p>
+                            <p class="title has-text-white">{format_number(repository.stargazers_count)}</p>
+                        </div>
+                    </ybc::LevelItem>
+                }
+            </ybc::Level>
+            <ybc::Subtitle size={ybc::HeaderSize::Is4} classes="has-text-white has-text-centered mt-6">
+                {"Downloads by platform"}
+            </ybc::Subtitle>
+            <PlatformChart totals={downloads_by_os(releases)}/>
+            </>
+        }
+    } else if releases.error.is_some() {
+        let onretry = {
+            let releases = releases.clone();
+            Callback::from(move |_| releases.run())
+        };
+        html! {
+            <>
+            <p class="has-text-centered">{"Failed to load project stats"}</p>
+            <Retry {onretry} cache_key={ALL_RELEASES_CACHE_KEY}/>
+            </>
+        }
+    } else {
+        Default::default()
+    };
+
+    html! {
+        <ybc::Hero
+            classes="is-dark"
+            size={ybc::HeroSize::FullheightWithNavbar}
+            body={html!{
+                <ybc::Container>
+                <ybc::Section>
+                    <HomeLogoLink/>
+                    <ybc::Title size={ybc::HeaderSize::Is2} classes="has-text-white has-text-centered">
+                        {"Project stats"}
+                    </ybc::Title>
+                    {content}
+                </ybc::Section>
+                </ybc::Container>
+            }}>
+        </ybc::Hero>
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use super::*;
+    use crate::services::release_source::StubSource;
+    use crate::fixtures::release_with;
+    use crate::testing::render;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn an_empty_release_list_says_so() {
+        let page = render(StubSource::empty().into_handle(), html! { <Stats/> });
+        assert!(page.wait_for("p.has-text-centered").await.is_some(), "no empty state rendered");
+        assert!(page.text().contains("No releases published yet — check back soon"), "{}", page.text());
+        assert!(page.select(".button").is_none(), "no retry should be offered for an empty list");
+    }
+
+    #[wasm_bindgen_test]
+    async fn the_strip_shows_releases_downloads_and_stars() {
+        let mut source = StubSource::with_latest(release_with("1.2.3", &["Pandora-1.2.3.dmg"]));
+        source.stars = 1234;
+        let page = render(source.into_handle(), html! { <Stats/> });
+        assert!(page.wait_for(".level").await.is_some(), "no stats strip rendered");
+        page.settle().await;
+
+        let headings: Vec<_> = page.select_all(".level .heading").iter()
+            .filter_map(|heading| heading.text_content())
+            .collect();
+        assert_eq!(headings, ["Releases", "Downloads", "Stars"]);
+        assert!(page.text().contains(&format_number(1234)), "{}", page.text());
+    }
+}
@@ -0,0 +1,171 @@
+use yew::prelude::*;
+use yew_hooks::{use_async_with_options, use_timeout, UseAsyncOptions};
+use yew_router::prelude::*;
+
+use crate::components::copy_button::CopyButton;
+use crate::components::logo::HomeLogoLink;
+use crate::components::release_notes::ReleaseNotes;
+use crate::components::retry::Retry;
+use crate::routes::{release_url, AppRoute};
+use crate::services::cache::use_refresh_when_visible;
+use crate::services::github::{GitHubReleases, ALL_RELEASES_CACHE_KEY, RELEASES_TTL_MILLIS};
+use crate::services::release_source::use_release_source;
+use crate::util::format_date;
+
+#[derive(Properties, PartialEq)]
+struct VersionRowParams {
+    release: GitHubReleases,
+    /// Called with the tag whose link was copied
+    oncopy: Callback<String>,
+}
+
+#[function_component(VersionRow)]
+fn version_row(params: &VersionRowParams) -> Html {
+    let tag = params.release.tag_name.to_string();
+    let published = params.release.published_at
+        .map(format_date)
+        .unwrap_or_default();
+    let oncopy = {
+        let tag = tag.clone();
+        params.oncopy.reform(move |_| tag.clone())
+    };
+
+    html! {
+        <div class="notification is-primary version-row">
+            <ybc::Level>
+                <ybc::LevelLeft>
+                    <ybc::LevelItem>
+                        <Link<AppRoute> to={AppRoute::release(&tag)} classes="has-text-white has-text-weight-bold">{params.release.display_name()}</Link<AppRoute>>
+                    </ybc::LevelItem>
+                    if params.release.has_title() {
+                        <ybc::LevelItem>
+                            <code>{&tag}</code>
+                        </ybc::LevelItem>
+                    }
+                    if params.release.prerelease {
+                        <ybc::LevelItem>
+                            <span class="tag is-warning">{"Pre-release"}</span>
+                        </ybc::LevelItem>
+                    }
+                    <ybc::LevelItem>
+                        <span class="is-size-7">{published}</span>
+                    </ybc::LevelItem>
+                </ybc::LevelLeft>
+                <ybc::LevelRight>
+                    <ybc::LevelItem>
+                        <CopyButton text={release_url(&tag)} label="Copy link" classes="is-dark" {oncopy}/>
+                    </ybc::LevelItem>
+                </ybc::LevelRight>
+            </ybc::Level>
+            if let Some(body) = params.release.body.as_deref().filter(|body| !body.trim().is_empty()) {
+                <ReleaseNotes body={body.to_string()}/>
+            }
+        </div>
+    }
+}
+
+/// Every release, newest first, each with a link that pins to that exact version
+#[function_component(Versions)]
+pub fn versions() -> Html {
+    let source = use_release_source();
+    let releases = use_async_with_options(
+        source.0.fetch_all(),
+        UseAsyncOptions::enable_auto()
+    );
+    use_refresh_when_visible(&releases, RELEASES_TTL_MILLIS);
+
+    // Confirms a copied version link, then hides itself again
+    let copied_tag = use_state(|| None::<String>);
+    let hide_toast = {
+        let copied_tag = copied_tag.clone();
+        use_timeout(move || copied_tag.set(None), 3000)
+    };
+    let oncopy = {
+        let copied_tag = copied_tag.clone();
+        Callback::from(move |tag| {
+            copied_tag.set(Some(tag));
+            hide_toast.reset();
+        })
+    };
+
+    let content = if releases.data.as_ref().is_some_and(Vec::is_empty) {
+        html! { <p class="has-text-centered">{"No releases published yet — check back soon"}</p> }
+    } else if let Some(releases) = &releases.data {
+        let mut releases = releases.clone();
+        releases.sort_by_key(|release| std::cmp::Reverse(release.published_at));
+
+        html! {
+            { for releases.into_iter().map(|release| html! { <VersionRow {release} oncopy={oncopy.clone()}/> }) }
+        }
+    } else if releases.error.is_some() {
+        let onretry = {
+            let releases = releases.clone();
+            Callback::from(move |_| releases.run())
+        };
+        html! {
+            <>
+            <p class="has-text-centered">{"Failed to load version history"}</p>
+            <Retry {onretry} cache_key={ALL_RELEASES_CACHE_KEY}/>
+            </>
+        }
+    } else {
+        Default::default()
+    };
+
+    html! {
+        <ybc::Hero
+            classes="is-dark"
+            size={ybc::HeroSize::FullheightWithNavbar}
+            body={html!{
+                <ybc::Container>
+                <ybc::Section>
+                    <HomeLogoLink/>
+                    <ybc::Title size={ybc::HeaderSize::Is2} classes="has-text-white has-text-centered">
+                        {"Version history"}
+                    </ybc::Title>
+                    {content}
+                    <div class="copy-toast" role="status" aria-live="polite">
+                        if let Some(tag) = &*copied_tag {
+                            <div class="notification is-success">{format!("Link to {} copied", tag)}</div>
+                        }
+                    </div>
+                </ybc::Section>
+                </ybc::Container>
+            }}>
+        </ybc::Hero>
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+    use web_sys::HtmlElement;
+
+    use super::*;
+    use crate::fixtures::release_with;
+    use crate::services::release_source::StubSource;
+    use crate::testing::render;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn an_empty_release_list_says_so() {
+        let page = render(StubSource::empty().into_handle(), html! { <Versions/> });
+        assert!(page.wait_for("p.has-text-centered").await.is_some(), "no empty state rendered");
+        assert!(page.text().contains("No releases published yet — check back soon"), "{}", page.text());
+        assert!(page.select(".button").is_none(), "no retry should be offered for an empty list");
+    }
+
+    #[wasm_bindgen_test]
+    async fn copying_a_version_link_shows_a_toast() {
+        let source = StubSource::with_latest(release_with("1.2.3", &[])).into_handle();
+        let page = render(source, html! { <Versions/> });
+        let button = page.wait_for(".version-row .button").await.expect("no copy link button");
+        assert!(page.select(".copy-toast .notification").is_none());
+
+        button.unchecked_into::<HtmlElement>().click();
+        let toast = page.wait_for(".copy-toast .notification").await.expect("no toast after copying");
+        assert_eq!(toast.text_content().as_deref(), Some("Link to 1.2.3 copied"));
+    }
+}
@@ -0,0 +1,113 @@
+use std::future::Future;
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use yew::prelude::*;
+use yew_hooks::{use_event_with_window, use_interval, UseAsyncHandle};
+
+#[derive(Serialize, Deserialize)]
+struct Entry<T> {
+    /// Milliseconds since the epoch, as reported by `Date.now()`
+    stored_at: f64,
+    value: T,
+}
+
+pub fn now_millis() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Returns the value stored under `key` if it is younger than `ttl_millis`
+pub fn get<T: DeserializeOwned>(key: &str, ttl_millis: f64) -> Option<T> {
+    let entry: Entry<T> = LocalStorage::get(key).ok()?;
+    (now_millis() - entry.stored_at < ttl_millis).then_some(entry.value)
+}
+
+pub fn set<T: Serialize>(key: &str, value: &T) {
+    let entry = Entry { stored_at: now_millis(), value };
+    if let Err(error) = LocalStorage::set(key, entry) {
+        log::warn!("Failed to cache {}: {}", key, error);
+    }
+}
+
+pub fn remove(key: &str) {
+    LocalStorage::delete(key);
+}
+
+/// Serves `key` from the cache while it is fresh, otherwise runs `fetch` and caches a successful result
+pub async fn cached<T, E, F>(key: &str, ttl_millis: f64, fetch: F) -> Result<T, E>
+where
+    T: Serialize + DeserializeOwned,
+    F: Future<Output = Result<T, E>>,
+{
+    if let Some(value) = get(key, ttl_millis) {
+        return Ok(value);
+    }
+
+    let value = fetch.await?;
+    set(key, &value);
+    Ok(value)
+}
+
+/// Refreshes the visitor didn't ask for are skipped when they turned
+/// auto-refresh off or GitHub's rate limit is nearly used up
+pub fn background_refresh_allowed() -> bool {
+    if super::request::rate_limit_low() {
+        log::debug!("Skipping a background refresh, the GitHub rate limit is low");
+        return false;
+    }
+    crate::preferences::auto_refresh_enabled()
+}
+
+/// Re-runs `handle` in the background when the tab becomes visible again and its
+/// data is older than `ttl_millis`, unless `background_refresh_allowed` says no.
+/// Previous data stays on screen while it reloads
+#[hook]
+pub fn use_refresh_when_visible<T, E>(handle: &UseAsyncHandle<T, E>, ttl_millis: f64)
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    let loaded_at = use_mut_ref(now_millis);
+
+    {
+        let loaded_at = loaded_at.clone();
+        use_effect_with_deps(move |loading| {
+            if !*loading {
+                *loaded_at.borrow_mut() = now_millis();
+            }
+        }, handle.loading);
+    }
+
+    let handle = handle.clone();
+    use_event_with_window("visibilitychange", move |_: Event| {
+        let visible = web_sys::window()
+            .and_then(|window| window.document())
+            .is_some_and(|document| !document.hidden());
+        if !visible || !background_refresh_allowed() {
+            return;
+        }
+        if !handle.loading && now_millis() - *loaded_at.borrow() > ttl_millis {
+            log::debug!("Refreshing stale data after the tab became visible");
+            handle.run();
+        }
+    });
+}
+
+/// Re-runs `handle` every `interval_millis` while the tab is visible, unless
+/// `background_refresh_allowed` says no. An interval of 0 disables polling
+#[hook]
+pub fn use_poll<T, E>(handle: &UseAsyncHandle<T, E>, interval_millis: u32)
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    let handle = handle.clone();
+    use_interval(move || {
+        let hidden = web_sys::window()
+            .and_then(|window| window.document())
+            .is_some_and(|document| document.hidden());
+        if !hidden && !handle.loading && background_refresh_allowed() {
+            handle.run();
+        }
+    }, interval_millis);
+}
@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use chrono::{DateTime, Utc};
+
+use super::cache;
+use super::request::{self, RequestError};
+use crate::version::Version;
+
+const REPOSITORY: &str = "Moulberry/PandoraLauncher";
+const REPOSITORY_API: &str = "https://api.github.com/repos/Moulberry/PandoraLauncher";
+const RELEASES_API: &str = "https://api.github.com/repos/Moulberry/PandoraLauncher/releases";
+pub const RELEASES_PAGE: &str = "https://github.com/Moulberry/PandoraLauncher/releases";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubReleases {
+    pub tag_name: Arc<str>,
+    /// Human title such as "Pandora 1.4.2 — Hotfix", often empty
+    #[serde(default)]
+    pub name: Option<Arc<str>>,
+    pub body: Option<Arc<str>>,
+    #[serde(default)]
+    pub prerelease: bool,
+    pub published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub assets: Vec<GitHubReleaseAsset>,
+    /// Set on the tag-only stand-in built from jsDelivr while GitHub can't be
+    /// reached. Never set for releases from the API, even ones without assets
+    #[serde(skip)]
+    pub from_fallback: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubReleaseAsset {
+    pub name: Arc<str>,
+    pub browser_download_url: Arc<str>,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub download_count: u64,
+    /// `"uploaded"` once the asset can be downloaded, `"uploading"` while a release is still being cut
+    #[serde(default = "default_asset_state")]
+    pub state: String,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+fn default_asset_state() -> String {
+    "uploaded".into()
+}
+
+impl GitHubReleaseAsset {
+    pub fn is_uploaded(&self) -> bool {
+        self.state == "uploaded"
+    }
+}
+
+const RELEASE_FIELDS: &[&str] = &["tag_name", "name", "body", "prerelease", "published_at", "assets"];
+const ASSET_FIELDS: &[&str] = &["name", "browser_download_url", "size", "download_count", "state", "updated_at"];
+
+/// Logs any fields GitHub left out so API changes show up in the console
+/// instead of as silently defaulted values
+fn warn_missing_fields(release: &Value) {
+    let tag = release.get("tag_name").and_then(Value::as_str).unwrap_or("<unknown>");
+    for field in RELEASE_FIELDS.iter().filter(|field| release.get(**field).is_none()) {
+        log::warn!("Release {} is missing `{}`", tag, field);
+    }
+
+    let assets = release.get("assets").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    for asset in assets {
+        let name = asset.get("name").and_then(Value::as_str).unwrap_or("<unknown>");
+        for field in ASSET_FIELDS.iter().filter(|field| asset.get(**field).is_none()) {
+            log::warn!("Asset {} in release {} is missing `{}`", name, tag, field);
+        }
+    }
+}
+
+fn parse_release(release: Value) -> Result<GitHubReleases, RequestError> {
+    warn_missing_fields(&release);
+    serde_json::from_value(release).map_err(|error| {
+        log::error!("Error deserializing release:\n{}", error);
+        RequestError::Deserialize(error.to_string())
+    })
+}
+
+async fn get_release(url: &str) -> Result<GitHubReleases, RequestError> {
+    parse_release(request::get(url).await?)
+}
+
+/// Releases that fail to parse are dropped rather than failing the whole list
+async fn get_releases(url: &str) -> Result<Vec<GitHubReleases>, RequestError> {
+    let releases: Vec<Value> = request::get(url).await?;
+    Ok(releases.into_iter().filter_map(|release| parse_release(release).ok()).collect())
+}
+
+pub const LATEST_RELEASE_CACHE_KEY: &str = "pandora.cache.latest_release";
+
+/// Newest tag jsDelivr knows of when the API was unreachable but the cached
+/// release is older, so the page can say a newer version exists
+const NEWER_TAG_KEY: &str = "pandora.cache.newer_tag";
+
+/// The newest tag seen through jsDelivr that the cached release is behind, if any
+pub fn newer_tag_than_cached() -> Option<String> {
+    LocalStorage::get(NEWER_TAG_KEY).ok()
+}
+
+/// A release with nothing but its tag, for when jsDelivr is all we have
+fn release_without_assets(tag: String) -> GitHubReleases {
+    GitHubReleases {
+        tag_name: tag.into(),
+        name: None,
+        body: None,
+        prerelease: false,
+        published_at: None,
+        assets: Vec::new(),
+        from_fallback: true,
+    }
+}
+
+/// `/latest` skips pre-releases and 404s when nothing else has been published,
+/// so early in a project the newest pre-release stands in for it
+async fn get_newest_prerelease() -> Result<GitHubReleases, RequestError> {
+    let releases = fetch_releases().await?;
+    releases.into_iter()
+        .filter(|release| release.prerelease)
+        .filter_map(|release| Some((release.version()?, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+        .ok_or(RequestError::Status(404, "No releases published".into()))
+}
+
+/// The latest release, falling back to the last one fetched successfully when
+/// GitHub can't be reached so downloads stay available during outages. If the
+/// API is blocked outright, jsDelivr is asked for the newest tag so a newer
+/// release than the cached one can be pointed out. While only pre-releases exist,
+/// the newest of those is returned instead
+pub async fn fetch_latest_release() -> Result<GitHubReleases, RequestError> {
+    let latest = match get_release(&format!("{}/latest", RELEASES_API)).await {
+        Err(error) if error.is_not_found() => {
+            log::info!("No stable release yet, looking for a pre-release");
+            get_newest_prerelease().await
+        },
+        latest => latest,
+    };
+    let error = match latest {
+        Ok(release) => {
+            cache::set(LATEST_RELEASE_CACHE_KEY, &release);
+            LocalStorage::delete(NEWER_TAG_KEY);
+            return Ok(release);
+        },
+        Err(error) => error,
+    };
+
+    let cached: Option<GitHubReleases> = cache::get(LATEST_RELEASE_CACHE_KEY, f64::INFINITY);
+    if error.is_network() {
+        match request::latest_tag_from_jsdelivr(REPOSITORY).await {
+            // The cached release still has working download links, so it stays on
+            // screen and the newer tag is only pointed out
+            Ok(tag) => match &cached {
+                Some(cached) if tag.parse::<Version>().ok() > cached.version() => {
+                    log::warn!("jsDelivr lists {}, newer than the cached {}, after: {}", tag, cached.tag_name, error);
+                    let _ = LocalStorage::set(NEWER_TAG_KEY, &tag);
+                },
+                Some(_) => LocalStorage::delete(NEWER_TAG_KEY),
+                None => {
+                    log::warn!("Using the latest tag from jsDelivr after: {}", error);
+                    return Ok(release_without_assets(tag));
+                },
+            },
+            Err(fallback_error) => log::warn!("jsDelivr fallback failed too: {}", fallback_error),
+        }
+    }
+
+    if cached.is_some() {
+        log::warn!("Using the last cached release after: {}", error);
+    }
+    cached.ok_or(error)
+}
+
+pub async fn fetch_release_by_tag(tag: &str) -> Result<GitHubReleases, RequestError> {
+    let tag = String::from(js_sys::encode_uri_component(tag));
+    get_release(&format!("{}/tags/{}", RELEASES_API, tag)).await
+}
+
+pub async fn fetch_releases() -> Result<Vec<GitHubReleases>, RequestError> {
+    get_releases(&format!("{}?per_page=100", RELEASES_API)).await
+}
+
+pub const ALL_RELEASES_CACHE_KEY: &str = "pandora.cache.all_releases";
+/// How long release data counts as fresh, both in the cache and on screen
+pub const RELEASES_TTL_MILLIS: f64 = 60.0 * 60.0 * 1000.0;
+const RELEASES_PER_PAGE: usize = 100;
+const MAX_RELEASE_PAGES: usize = 10;
+
+/// Every published release, following pagination. Cached for an hour since it
+/// costs one API request per page
+pub async fn fetch_all_releases() -> Result<Vec<GitHubReleases>, RequestError> {
+    cache::cached(ALL_RELEASES_CACHE_KEY, RELEASES_TTL_MILLIS, async {
+        let mut releases = Vec::new();
+        for page in 1..=MAX_RELEASE_PAGES {
+            let url = format!("{}?per_page={}&page={}", RELEASES_API, RELEASES_PER_PAGE, page);
+            let page: Vec<Value> = request::get(&url).await?;
+            let last_page = page.len() < RELEASES_PER_PAGE;
+            let page = page.into_iter().filter_map(|release| parse_release(release).ok());
+            releases.extend(page);
+            if last_page {
+                break;
+            }
+        }
+        Ok(releases)
+    }).await
+}
+
+/// The parts of the repository's metadata the site shows
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GitHubRepository {
+    #[serde(default)]
+    pub stargazers_count: u64,
+}
+
+pub const REPOSITORY_CACHE_KEY: &str = "pandora.cache.repository";
+
+/// Repository metadata such as the star count, cached as long as the release list
+pub async fn fetch_repository() -> Result<GitHubRepository, RequestError> {
+    cache::cached(REPOSITORY_CACHE_KEY, RELEASES_TTL_MILLIS, request::get(REPOSITORY_API)).await
+}
+
+/// The release list if `fetch_all_releases` cached it within the last hour, without a request
+pub fn cached_all_releases() -> Option<Vec<GitHubReleases>> {
+    cache::get(ALL_RELEASES_CACHE_KEY, RELEASES_TTL_MILLIS)
+}
+
+impl GitHubReleases {
+    pub fn version(&self) -> Option<Version> {
+        self.tag_name.parse().ok()
+    }
+
+    /// The release's title for headlines, or its tag when it has none.
+    /// Copying and comparing still go by `tag_name`
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().map(str::trim).filter(|name| !name.is_empty()).unwrap_or(&self.tag_name)
+    }
+
+    /// Whether `display_name` is a title rather than the tag itself, so the tag needs showing too
+    pub fn has_title(&self) -> bool {
+        self.display_name() != &*self.tag_name
+    }
+}
+
+/// Newest stable release, plus the newest pre-release if it is newer than that stable one
+pub fn latest_channels(releases: &[GitHubReleases]) -> (Option<&GitHubReleases>, Option<&GitHubReleases>) {
+    let newest = |prerelease: bool| {
+        releases.iter()
+            .filter(|release| release.prerelease == prerelease)
+            .filter_map(|release| Some((release.version()?, release)))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+    };
+
+    let stable = newest(false);
+    let beta = newest(true).filter(|(beta_version, _)| {
+        stable.as_ref().is_none_or(|(stable_version, _)| beta_version > stable_version)
+    });
+
+    (stable.map(|(_, release)| release), beta.map(|(_, release)| release))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimal_release_uses_defaults() {
+        let release: GitHubReleases = serde_json::from_str(r#"{ "tag_name": "1.2.3" }"#).unwrap();
+        assert_eq!(&*release.tag_name, "1.2.3");
+        assert_eq!(release.name, None);
+        assert_eq!(release.body, None);
+        assert!(!release.prerelease);
+        assert_eq!(release.published_at, None);
+        assert!(release.assets.is_empty());
+    }
+
+    #[test]
+    fn minimal_asset_uses_defaults() {
+        let asset: GitHubReleaseAsset = serde_json::from_str(
+            r#"{ "name": "Pandora.dmg", "browser_download_url": "https://github.com/Pandora.dmg" }"#
+        ).unwrap();
+        assert_eq!(asset.size, 0);
+        assert_eq!(asset.download_count, 0);
+        assert!(asset.is_uploaded());
+        assert_eq!(asset.updated_at, None);
+    }
+
+    #[test]
+    fn partial_release_parses() {
+        let release = parse_release(serde_json::json!({
+            "tag_name": "1.2.3",
+            "body": null,
+            "assets": [{ "name": "Pandora.dmg", "browser_download_url": "https://github.com/Pandora.dmg", "size": 10 }],
+            "some_new_field": true,
+        })).unwrap();
+        assert_eq!(release.assets.len(), 1);
+        assert_eq!(release.assets[0].size, 10);
+    }
+
+    #[test]
+    fn only_the_jsdelivr_stand_in_counts_as_a_fallback() {
+        let empty: GitHubReleases = serde_json::from_str(r#"{ "tag_name": "1.2.3", "assets": [] }"#).unwrap();
+        assert!(!empty.from_fallback);
+        assert!(release_without_assets("1.2.3".into()).from_fallback);
+
+        let serialized = serde_json::to_value(release_without_assets("1.2.3".into())).unwrap();
+        assert!(serialized.get("from_fallback").is_none());
+    }
+
+    #[test]
+    fn repository_keeps_only_the_star_count() {
+        let repository: GitHubRepository = serde_json::from_value(serde_json::json!({
+            "full_name": "Moulberry/PandoraLauncher",
+            "stargazers_count": 1234,
+        })).unwrap();
+        assert_eq!(repository.stargazers_count, 1234);
+    }
+
+    #[test]
+    fn release_without_a_tag_is_rejected() {
+        assert!(matches!(parse_release(serde_json::json!({})), Err(RequestError::Deserialize(_))));
+    }
+}
@@ -1 +1,7 @@
+pub mod cache;
+pub mod github;
+pub mod push;
+pub mod reachability;
+pub mod release_source;
 pub mod request;
+pub mod telemetry;
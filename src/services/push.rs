@@ -0,0 +1,65 @@
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Notification, NotificationPermission, PushSubscriptionOptionsInit, ServiceWorkerRegistration};
+
+use super::request;
+use crate::config;
+
+const SERVICE_WORKER_PATH: &str = "/sw.js";
+
+fn js_error(error: JsValue) -> String {
+    error.as_string().unwrap_or_else(|| format!("{:?}", error))
+}
+
+/// Whether this deployment has a push backend to register subscriptions with
+pub fn push_configured() -> bool {
+    config::PUSH_ENDPOINT.is_some() && config::VAPID_PUBLIC_KEY.is_some()
+}
+
+/// Whether the browser has everything Web Push needs
+pub fn push_supported() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    let has = |target: &JsValue, property: &str| js_sys::Reflect::has(target, &JsValue::from_str(property)).unwrap_or(false);
+    has(&window.navigator(), "serviceWorker") && has(&window, "PushManager") && has(&window, "Notification")
+}
+
+pub fn permission_denied() -> bool {
+    push_supported() && Notification::permission() == NotificationPermission::Denied
+}
+
+/// Asks for notification permission, subscribes through the service worker and
+/// hands the subscription to the push backend
+pub async fn subscribe() -> Result<(), String> {
+    let (Some(endpoint), Some(vapid_public_key), Some(window)) = (config::PUSH_ENDPOINT, config::VAPID_PUBLIC_KEY, web_sys::window()) else {
+        return Err("Release notifications aren't set up on this site".into());
+    };
+
+    let permission = JsFuture::from(Notification::request_permission().map_err(js_error)?).await.map_err(js_error)?;
+    if permission.as_string().as_deref() != Some("granted") {
+        return Err("Notification permission was not granted".into());
+    }
+
+    let container = window.navigator().service_worker();
+    JsFuture::from(container.register(SERVICE_WORKER_PATH)).await.map_err(js_error)?;
+    let registration: ServiceWorkerRegistration = JsFuture::from(container.ready().map_err(js_error)?)
+        .await
+        .map_err(js_error)?
+        .unchecked_into();
+
+    let options = PushSubscriptionOptionsInit::new();
+    options.set_user_visible_only(true);
+    options.set_application_server_key(&JsValue::from_str(vapid_public_key));
+    let push_manager = registration.push_manager().map_err(js_error)?;
+    let subscription = JsFuture::from(push_manager.subscribe_with_options(&options).map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+
+    let subscription = js_sys::JSON::stringify(&subscription).map_err(js_error)?;
+    let subscription: serde_json::Value = serde_json::from_str(&String::from(subscription)).map_err(|error| error.to_string())?;
+    request::post(endpoint.to_string(), subscription)
+        .await
+        .map(|_| ())
+        .map_err(|status| format!("The notification server responded with {}", status))
+}
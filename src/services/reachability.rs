@@ -0,0 +1,43 @@
+use yew::prelude::*;
+
+use super::request;
+
+/// A tiny endpoint on the API host itself, since that is the host release data
+/// comes from and the one some networks block while github.com still loads
+const PROBE_URL: &str = "https://api.github.com/zen";
+
+/// Whether GitHub could be reached, shared through context so pages can skip
+/// straight to their fallback state instead of waiting on a doomed request
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BackendStatus {
+    /// `None` until the startup check has finished
+    pub reachable: Option<bool>,
+}
+
+impl BackendStatus {
+    pub fn is_unreachable(self) -> bool {
+        self.reachable == Some(false)
+    }
+}
+
+#[hook]
+pub fn use_backend_status() -> BackendStatus {
+    let status = use_state(BackendStatus::default);
+
+    {
+        let status = status.clone();
+        use_effect_with_deps(move |_| {
+            let online = web_sys::window().is_none_or(|window| window.navigator().on_line());
+            if online {
+                yew::platform::spawn_local(async move {
+                    let reachable = request::probe(PROBE_URL).await;
+                    status.set(BackendStatus { reachable: Some(reachable) });
+                });
+            } else {
+                status.set(BackendStatus { reachable: Some(false) });
+            }
+        }, ());
+    }
+
+    *status
+}
@@ -0,0 +1,119 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use super::github::{self, GitHubReleases, GitHubRepository};
+use super::request::RequestError;
+
+pub type ReleaseFuture = Pin<Box<dyn Future<Output = Result<GitHubReleases, RequestError>>>>;
+pub type ReleasesFuture = Pin<Box<dyn Future<Output = Result<Vec<GitHubReleases>, RequestError>>>>;
+pub type RepositoryFuture = Pin<Box<dyn Future<Output = Result<GitHubRepository, RequestError>>>>;
+
+/// Where pages get release data from. GitHub's API by default, but anything
+/// that can produce a `GitHubReleases`, such as a stub serving fixed JSON,
+/// can be provided through context instead
+pub trait ReleaseSource {
+    fn fetch_latest(&self) -> ReleaseFuture;
+    /// Every published release, in any order
+    fn fetch_all(&self) -> ReleasesFuture;
+    /// What `fetch_all` would return, if that is known without a request
+    fn cached_all(&self) -> Option<Vec<GitHubReleases>>;
+    /// The repository the releases belong to, for its star count
+    fn fetch_repository(&self) -> RepositoryFuture;
+}
+
+/// The GitHub API, with its cache and jsDelivr fallbacks
+pub struct GitHubSource;
+
+impl ReleaseSource for GitHubSource {
+    fn fetch_latest(&self) -> ReleaseFuture {
+        Box::pin(github::fetch_latest_release())
+    }
+
+    fn fetch_all(&self) -> ReleasesFuture {
+        Box::pin(github::fetch_all_releases())
+    }
+
+    fn cached_all(&self) -> Option<Vec<GitHubReleases>> {
+        github::cached_all_releases()
+    }
+
+    fn fetch_repository(&self) -> RepositoryFuture {
+        Box::pin(github::fetch_repository())
+    }
+}
+
+/// Context value wrapping the active source. Two handles are equal when they
+/// share the same source
+#[derive(Clone)]
+pub struct ReleaseSourceHandle(pub Rc<dyn ReleaseSource>);
+
+impl PartialEq for ReleaseSourceHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Default for ReleaseSourceHandle {
+    fn default() -> Self {
+        ReleaseSourceHandle(Rc::new(GitHubSource))
+    }
+}
+
+/// The provided release source, or GitHub when none is provided
+#[hook]
+pub fn use_release_source() -> ReleaseSourceHandle {
+    let provided = use_context::<ReleaseSourceHandle>();
+    let fallback = use_memo(|_| ReleaseSourceHandle::default(), ());
+    provided.unwrap_or_else(|| (*fallback).clone())
+}
+
+/// Serves fixed releases, for rendering pages in tests without touching the network
+#[cfg(all(test, target_arch = "wasm32"))]
+pub struct StubSource {
+    /// `None` answers like GitHub does when nothing is published: a 404
+    pub latest: Option<GitHubReleases>,
+    pub releases: Vec<GitHubReleases>,
+    pub stars: u64,
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+impl StubSource {
+    /// A source whose latest release is the one release in the list
+    pub fn with_latest(latest: GitHubReleases) -> Self {
+        StubSource { latest: Some(latest.clone()), releases: vec![latest], stars: 0 }
+    }
+
+    /// A repository with nothing published yet
+    pub fn empty() -> Self {
+        StubSource { latest: None, releases: Vec::new(), stars: 0 }
+    }
+
+    pub fn into_handle(self) -> ReleaseSourceHandle {
+        ReleaseSourceHandle(Rc::new(self))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+impl ReleaseSource for StubSource {
+    fn fetch_latest(&self) -> ReleaseFuture {
+        let latest = self.latest.clone().ok_or_else(|| RequestError::Status(404, "Not Found".into()));
+        Box::pin(async move { latest })
+    }
+
+    fn fetch_all(&self) -> ReleasesFuture {
+        let releases = self.releases.clone();
+        Box::pin(async move { Ok(releases) })
+    }
+
+    fn cached_all(&self) -> Option<Vec<GitHubReleases>> {
+        Some(self.releases.clone())
+    }
+
+    fn fetch_repository(&self) -> RepositoryFuture {
+        let repository = GitHubRepository { stargazers_count: self.stars };
+        Box::pin(async move { Ok(repository) })
+    }
+}
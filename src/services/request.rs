@@ -1,28 +1,242 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use gloo::storage::{LocalStorage, Storage};
 use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+use crate::version::Version;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RequestError {
+    #[error("network error: {0}")]
+    Network(String),
+    #[error("unexpected status {0} ({1})")]
+    Status(u16, String),
+    #[error("invalid response body: {0}")]
+    Deserialize(String),
+    /// A successful response that isn't JSON, typically a proxy or captive portal page
+    #[error("unexpected content type: {0}")]
+    UnexpectedContentType(String),
+}
+
+impl RequestError {
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, RequestError::Status(404, _))
+    }
 
-pub async fn get<T>(url: &str) -> Option<T>
+    /// The request never got a response, e.g. because the host is blocked
+    pub fn is_network(&self) -> bool {
+        matches!(self, RequestError::Network(_))
+    }
+}
+
+const RATE_LIMIT_KEY: &str = "pandora.rate_limit";
+/// Background refreshes stop once this few API requests remain, keeping the
+/// rest for things the visitor explicitly asks for
+const LOW_RATE_LIMIT_REMAINING: u32 = 10;
+
+/// GitHub's `X-RateLimit-*` headers from the most recent API response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct RateLimit {
+    remaining: u32,
+    /// Seconds since the epoch when the budget refills
+    reset: u64,
+}
+
+fn record_rate_limit(headers: &gloo_net::http::Headers) {
+    let remaining = headers.get("x-ratelimit-remaining").and_then(|remaining| remaining.parse().ok());
+    let reset = headers.get("x-ratelimit-reset").and_then(|reset| reset.parse().ok());
+    if let (Some(remaining), Some(reset)) = (remaining, reset) {
+        let _ = LocalStorage::set(RATE_LIMIT_KEY, RateLimit { remaining, reset });
+    }
+}
+
+/// Whether the last API response left too little budget for background
+/// refreshes before the limit resets
+pub fn rate_limit_low() -> bool {
+    let Ok(limit) = LocalStorage::get::<RateLimit>(RATE_LIMIT_KEY) else {
+        return false;
+    };
+    let resets_at_millis = limit.reset as f64 * 1000.0;
+    limit.remaining < LOW_RATE_LIMIT_REMAINING && js_sys::Date::now() < resets_at_millis
+}
+
+type SharedResponse = Rc<RefCell<Option<Result<Value, RequestError>>>>;
+
+/// Requests keyed by URL that are still waiting on a response
+struct InFlight<T> {
+    requests: HashMap<String, T>,
+}
+
+impl<T: Clone> InFlight<T> {
+    fn new() -> Self {
+        InFlight { requests: HashMap::new() }
+    }
+
+    /// The pending request for `url`, calling `start` only if there is none
+    fn join_or_start(&mut self, url: &str, start: impl FnOnce() -> T) -> T {
+        self.requests.entry(url.to_string()).or_insert_with(start).clone()
+    }
+
+    /// Forgets the request for `url` once it has its response, so later calls fetch afresh
+    fn finish(&mut self, url: &str) {
+        self.requests.remove(url);
+    }
+}
+
+thread_local! {
+    /// GETs still waiting on a response, so concurrent callers asking for the
+    /// same URL share one request instead of each sending their own
+    static IN_FLIGHT: RefCell<InFlight<(js_sys::Promise, SharedResponse)>> = RefCell::new(InFlight::new());
+}
+
+pub async fn get<T>(url: &str) -> Result<T, RequestError>
 where
     T: DeserializeOwned + 'static + std::fmt::Debug,
 {
-    let request = gloo_net::http::Request::get(&url).send().await;
+    let value = get_shared(url).await?;
+    serde_json::from_value(value).map_err(|error| {
+        log::error!("Error deserializing {}:\n{}", url, error);
+        RequestError::Deserialize(error.to_string())
+    })
+}
+
+/// Joins the in-flight request for `url`, starting one if there is none. The
+/// entry is removed once the response arrives, so later calls fetch afresh
+async fn get_shared(url: &str) -> Result<Value, RequestError> {
+    let (promise, response) = IN_FLIGHT.with(|in_flight| {
+        in_flight.borrow_mut().join_or_start(url, || start_get(url.to_string()))
+    });
+
+    let _ = JsFuture::from(promise).await;
+    let response = response.borrow().clone();
+    response.unwrap_or_else(|| Err(RequestError::Network("request was dropped".into())))
+}
+
+fn start_get(url: String) -> (js_sys::Promise, SharedResponse) {
+    let response = SharedResponse::default();
+    let promise = {
+        let response = response.clone();
+        future_to_promise(async move {
+            *response.borrow_mut() = Some(fetch_json(&url).await);
+            IN_FLIGHT.with(|in_flight| in_flight.borrow_mut().finish(&url));
+            Ok(JsValue::UNDEFINED)
+        })
+    };
+    (promise, response)
+}
+
+async fn fetch_json(url: &str) -> Result<Value, RequestError> {
+    let request = gloo_net::http::Request::get(url).send().await;
     match request {
         Ok(response) => {
+            record_rate_limit(&response.headers());
+            let content_type = response.headers().get("content-type");
+            if let Some(content_type) = content_type.filter(|content_type| response.ok() && !content_type.contains("json")) {
+                log::error!("Expected JSON from {}, got {}", url, content_type);
+                return Err(RequestError::UnexpectedContentType(content_type));
+            }
+
             if response.ok() {
-                match response.json().await {
-                    Ok(result) => Some(result),
+                match response.binary().await {
+                    Ok(body) => parse_json_body(url, &body),
                     Err(error) => {
-                        log::error!("Error deserializing {}:\n{}", url, error);
-                        None
+                        log::error!("Error reading {}:\n{}", url, error);
+                        Err(RequestError::Network(error.to_string()))
                     },
                 }
             } else {
                 log::error!("Error fetching {}: {} ({})", url, response.status(), response.status_text());
-                None
+                Err(RequestError::Status(response.status(), response.status_text()))
             }
         }
         Err(error) => {
             log::error!("Failed to fetch {}:\n{}", url, error);
-            None
+            Err(RequestError::Network(error.to_string()))
+        }
+    }
+}
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const UTF8_BOM: &[u8] = &[0xef, 0xbb, 0xbf];
+/// How much of an unparseable body to log with `?debug=1`
+const LOGGED_BODY_CHARS: usize = 200;
+
+/// Parses a response body read as raw bytes, so a body the browser didn't
+/// decode fails with an error saying what it actually was rather than a bare
+/// syntax error. A leading byte order mark is skipped and invalid UTF-8 is
+/// replaced instead of rejected outright
+fn parse_json_body(url: &str, body: &[u8]) -> Result<Value, RequestError> {
+    if body.starts_with(GZIP_MAGIC) {
+        log::error!("{} returned a gzip body that wasn't decoded", url);
+        return Err(RequestError::Deserialize("response is still gzip-compressed".into()));
+    }
+
+    let body = body.strip_prefix(UTF8_BOM).unwrap_or(body);
+    let text = String::from_utf8_lossy(body);
+    serde_json::from_str(&text).map_err(|error| {
+        log::error!("Error deserializing {}:\n{}", url, error);
+        // The logger only lets debug messages through with `?debug=1`
+        if log::log_enabled!(log::Level::Debug) {
+            let start: String = text.chars().take(LOGGED_BODY_CHARS).collect();
+            log::debug!("Start of the body from {}: {:?}", url, start);
+        }
+        RequestError::Deserialize(if text.trim().is_empty() {
+            "empty response body".into()
+        } else {
+            error.to_string()
+        })
+    })
+}
+
+/// jsDelivr's package API, which lists a GitHub repository's tags and is often
+/// reachable where api.github.com is blocked
+const JSDELIVR_PACKAGE_API: &str = "https://data.jsdelivr.com/v1/packages/gh";
+
+#[derive(Debug, Deserialize)]
+struct JsDelivrPackage {
+    versions: Vec<JsDelivrVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsDelivrVersion {
+    version: String,
+}
+
+/// Newest stable tag of `repository` (`owner/name`) according to jsDelivr.
+/// Only meant as a fallback after the GitHub API failed with a network error,
+/// since jsDelivr mirrors tags but not releases or their assets
+pub async fn latest_tag_from_jsdelivr(repository: &str) -> Result<String, RequestError> {
+    let package: JsDelivrPackage = get(&format!("{}/{}", JSDELIVR_PACKAGE_API, repository)).await?;
+    newest_stable_tag(package.versions.into_iter().map(|version| version.version))
+        .ok_or_else(|| RequestError::Deserialize("jsDelivr listed no release tags".into()))
+}
+
+fn newest_stable_tag(tags: impl Iterator<Item = String>) -> Option<String> {
+    tags.filter_map(|tag| Some((tag.parse::<Version>().ok()?, tag)))
+        .filter(|(version, _)| version.pre.is_empty())
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag)
+}
+
+/// Checks whether `url` can be reached at all. Uses an opaque `no-cors` request
+/// so it works against any host, which means only network failures are detected
+pub async fn probe(url: &str) -> bool {
+    let request = gloo_net::http::Request::new(url)
+        .method(gloo_net::http::Method::HEAD)
+        .mode(gloo_net::http::RequestMode::NoCors)
+        .send()
+        .await;
+    match request {
+        Ok(_) => true,
+        Err(error) => {
+            log::warn!("Failed to reach {}:\n{}", url, error);
+            false
         }
     }
 }
@@ -46,3 +260,81 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn concurrent_requests_for_one_url_share_a_request() {
+        let mut in_flight = InFlight::new();
+        let started = Cell::new(0);
+        let start = || {
+            started.set(started.get() + 1);
+            Rc::new(started.get())
+        };
+
+        let first = in_flight.join_or_start("https://api.github.com/a", start);
+        let second = in_flight.join_or_start("https://api.github.com/a", start);
+        assert_eq!(started.get(), 1);
+        assert!(Rc::ptr_eq(&first, &second));
+
+        let other = in_flight.join_or_start("https://api.github.com/b", start);
+        assert_eq!(started.get(), 2);
+        assert!(!Rc::ptr_eq(&first, &other));
+    }
+
+    #[test]
+    fn finished_requests_are_sent_again() {
+        let mut in_flight = InFlight::new();
+        let started = Cell::new(0);
+        let start = || {
+            started.set(started.get() + 1);
+            started.get()
+        };
+
+        in_flight.join_or_start("https://api.github.com/a", start);
+        in_flight.finish("https://api.github.com/a");
+        assert_eq!(in_flight.join_or_start("https://api.github.com/a", start), 2);
+    }
+
+    #[test]
+    fn newest_stable_tag_skips_pre_releases() {
+        let tags = ["1.2.0", "1.10.0", "2.0.0-beta.1", "not-a-version"].iter().map(|tag| tag.to_string());
+        assert_eq!(newest_stable_tag(tags).as_deref(), Some("1.10.0"));
+        assert_eq!(newest_stable_tag(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn parses_valid_json() {
+        let value = parse_json_body("test", br#"{ "tag_name": "1.2.3" }"#).unwrap();
+        assert_eq!(value["tag_name"], "1.2.3");
+    }
+
+    #[test]
+    fn skips_a_byte_order_mark() {
+        let value = parse_json_body("test", b"\xef\xbb\xbf[1, 2]").unwrap();
+        assert_eq!(value, serde_json::json!([1, 2]));
+    }
+
+    #[test]
+    fn reports_undecoded_gzip() {
+        let error = parse_json_body("test", &[0x1f, 0x8b, 0x08, 0x00, 0x00]).unwrap_err();
+        assert_eq!(error, RequestError::Deserialize("response is still gzip-compressed".into()));
+    }
+
+    #[test]
+    fn replaces_invalid_utf8() {
+        let value = parse_json_body("test", b"{ \"name\": \"Pandora \xff\" }").unwrap();
+        assert_eq!(value["name"], "Pandora \u{fffd}");
+    }
+
+    #[test]
+    fn reports_empty_and_malformed_bodies() {
+        assert_eq!(parse_json_body("test", b"  ").unwrap_err(), RequestError::Deserialize("empty response body".into()));
+        assert!(matches!(parse_json_body("test", b"{ \"tag_name\": "), Err(RequestError::Deserialize(_))));
+        assert!(matches!(parse_json_body("test", b"<html>"), Err(RequestError::Deserialize(_))));
+    }
+}
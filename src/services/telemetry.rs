@@ -0,0 +1,79 @@
+use gloo::storage::{LocalStorage, Storage};
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+
+use super::request;
+use crate::config::{self, FeatureFlags};
+use crate::platform::OperatingSystem;
+
+pub const OPT_OUT_KEY: &str = "pandora.analytics_opt_out";
+
+/// Whether the browser asks not to be tracked, via Do Not Track or Global Privacy Control
+pub fn browser_opted_out() -> bool {
+    let Some(window) = web_sys::window() else {
+        return true;
+    };
+    let navigator = window.navigator();
+
+    let do_not_track = navigator.do_not_track() == "1";
+    let global_privacy_control = js_sys::Reflect::get(&navigator, &JsValue::from_str("globalPrivacyControl"))
+        .map(|value| value.is_truthy())
+        .unwrap_or(false);
+
+    do_not_track || global_privacy_control
+}
+
+/// Whether any reporting endpoint is configured at all
+pub fn reporting_configured() -> bool {
+    config::TELEMETRY_ENDPOINT.is_some() || config::PAGEVIEW_ENDPOINT.is_some()
+}
+
+/// Checked before sending anything: requires the analytics feature and no
+/// opt-out from either the browser or the in-page toggle
+pub fn analytics_enabled(flags: FeatureFlags) -> bool {
+    if !flags.analytics || browser_opted_out() {
+        return false;
+    }
+    !LocalStorage::get::<bool>(OPT_OUT_KEY).unwrap_or(false)
+}
+
+#[derive(Serialize)]
+struct Pageview {
+    route: &'static str,
+}
+
+/// Counts a page view on the self-hosted endpoint. Only the route name is sent
+pub fn report_pageview(flags: FeatureFlags, route: &'static str) {
+    let Some(endpoint) = config::PAGEVIEW_ENDPOINT else {
+        return;
+    };
+    if !analytics_enabled(flags) {
+        return;
+    }
+
+    yew::platform::spawn_local(async move {
+        if let Err(status) = request::post(endpoint.to_string(), Pageview { route }).await {
+            log::warn!("Failed to report page view: {}", status);
+        }
+    });
+}
+
+/// Reports that the visitor picked a different OS than the one we detected.
+/// Only the two OS identifiers are sent, nothing about the visitor themselves
+pub fn report_os_override(flags: FeatureFlags, detected: OperatingSystem, chosen: OperatingSystem) {
+    let body = format!("detected={}&chosen={}", detected.id(), chosen.id());
+    send_beacon(flags, &body);
+}
+
+fn send_beacon(flags: FeatureFlags, body: &str) {
+    if !analytics_enabled(flags) {
+        return;
+    }
+    let (Some(endpoint), Some(window)) = (config::TELEMETRY_ENDPOINT, web_sys::window()) else {
+        return;
+    };
+
+    if let Err(error) = window.navigator().send_beacon_with_opt_str(endpoint, Some(body)) {
+        log::warn!("Failed to send beacon: {:?}", error);
+    }
+}
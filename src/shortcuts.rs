@@ -0,0 +1,80 @@
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent, ScrollBehavior, ScrollIntoViewOptions};
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+
+/// Id of the download link focused by the jump-to-downloads shortcut
+pub const PRIMARY_DOWNLOAD_ID: &str = "primary-download";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shortcut {
+    pub key: &'static str,
+    pub description: &'static str,
+}
+
+/// Every shortcut handled by [`use_keyboard_shortcuts`], as listed in the help overlay
+pub fn shortcuts() -> Vec<Shortcut> {
+    vec![
+        Shortcut { key: "d", description: "Jump to the recommended download" },
+        Shortcut { key: "?", description: "Show this list of shortcuts" },
+        Shortcut { key: "Esc", description: "Close this list" },
+    ]
+}
+
+/// Registers the page-wide keyboard shortcuts and returns whether the shortcut
+/// help overlay is open. Keys typed into form fields or combined with
+/// modifiers are ignored
+#[hook]
+pub fn use_keyboard_shortcuts() -> UseStateHandle<bool> {
+    let help_open = use_state(|| false);
+
+    {
+        let help_open = help_open.clone();
+        use_event_with_window("keydown", move |event: KeyboardEvent| {
+            if event.ctrl_key() || event.meta_key() || event.alt_key() || is_typing(&event) {
+                return;
+            }
+
+            match event.key().as_str() {
+                "d" => {
+                    event.prevent_default();
+                    help_open.set(false);
+                    jump_to_downloads();
+                },
+                "?" => {
+                    event.prevent_default();
+                    help_open.set(!*help_open);
+                },
+                _ => {},
+            }
+        });
+    }
+
+    help_open
+}
+
+fn is_typing(event: &KeyboardEvent) -> bool {
+    let Some(target) = event.target().and_then(|target| target.dyn_into::<HtmlElement>().ok()) else {
+        return false;
+    };
+    matches!(target.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT") || target.is_content_editable()
+}
+
+fn jump_to_downloads() {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+
+    if let Some(section) = document.get_element_by_id("downloads") {
+        let options = ScrollIntoViewOptions::new();
+        options.set_behavior(ScrollBehavior::Smooth);
+        section.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+
+    let target = document
+        .get_element_by_id(PRIMARY_DOWNLOAD_ID)
+        .or_else(|| document.query_selector("#downloads ~ * a[download]").ok().flatten());
+    if let Some(target) = target.and_then(|target| target.dyn_into::<HtmlElement>().ok()) {
+        let _ = target.focus();
+    }
+}
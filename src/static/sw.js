@@ -0,0 +1,18 @@
+// Push-only service worker: shows release notifications sent by the push
+// backend configured through PANDORA_PUSH_ENDPOINT. Nothing is cached here.
+
+self.addEventListener("push", (event) => {
+    const data = event.data ? event.data.json() : {};
+    event.waitUntil(
+        self.registration.showNotification(data.title || "A new Pandora version is out", {
+            body: data.body || "",
+            icon: "pandora_logo.svg",
+            data: { url: data.url || self.registration.scope },
+        })
+    );
+});
+
+self.addEventListener("notificationclick", (event) => {
+    event.notification.close();
+    event.waitUntil(clients.openWindow(event.notification.data.url));
+});
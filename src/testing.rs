@@ -0,0 +1,92 @@
+//! Helpers for the component tests, which render into a real document and run
+//! with `cargo test --target wasm32-unknown-unknown` in a headless browser
+
+use gloo::timers::future::TimeoutFuture;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+use yew::AppHandle;
+use yew_router::prelude::*;
+
+use crate::services::release_source::ReleaseSourceHandle;
+
+/// How long to wait for a selector before giving up on it
+const WAIT_MILLIS: u32 = 2000;
+
+#[derive(Properties, PartialEq)]
+struct HarnessParams {
+    source: ReleaseSourceHandle,
+    children: Children,
+}
+
+/// What `App` provides that the pages need, with the release source swapped out
+#[function_component(Harness)]
+fn harness(params: &HarnessParams) -> Html {
+    html! {
+        <ContextProvider<ReleaseSourceHandle> context={params.source.clone()}>
+            <BrowserRouter>
+                { for params.children.iter() }
+            </BrowserRouter>
+        </ContextProvider<ReleaseSourceHandle>>
+    }
+}
+
+/// A component rendered into its own element, unmounted and removed again when dropped
+pub struct Rendered {
+    pub root: Element,
+    app: Option<AppHandle<Harness>>,
+}
+
+impl Drop for Rendered {
+    fn drop(&mut self) {
+        if let Some(app) = self.app.take() {
+            app.destroy();
+        }
+        self.root.remove();
+    }
+}
+
+impl Rendered {
+    /// Waits for the fetches and effects that put `selector` on the page
+    pub async fn wait_for(&self, selector: &str) -> Option<Element> {
+        for _ in 0..WAIT_MILLIS / 10 {
+            if let Some(element) = self.select(selector) {
+                return Some(element);
+            }
+            TimeoutFuture::new(10).await;
+        }
+        None
+    }
+
+    /// Lets pending fetches resolve and the page settle
+    pub async fn settle(&self) {
+        TimeoutFuture::new(50).await;
+    }
+
+    pub fn select(&self, selector: &str) -> Option<Element> {
+        self.root.query_selector(selector).unwrap()
+    }
+
+    pub fn select_all(&self, selector: &str) -> Vec<Element> {
+        let nodes = self.root.query_selector_all(selector).unwrap();
+        (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<Element>().ok())
+            .collect()
+    }
+
+    pub fn text(&self) -> String {
+        self.root.text_content().unwrap_or_default()
+    }
+}
+
+/// Renders `content` against `source` into a fresh element of the document body
+pub fn render(source: ReleaseSourceHandle, content: Html) -> Rendered {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+
+    let props = HarnessParams { source, children: Children::new(vec![content]) };
+    let app = yew::Renderer::<Harness>::with_root_and_props(root.clone(), props).render();
+    Rendered { root, app: Some(app) }
+}
@@ -0,0 +1,50 @@
+use gloo::storage::{LocalStorage, Storage};
+use wasm_bindgen::JsCast;
+
+use crate::config::{LINK_COLOR, PRIMARY_COLOR};
+use crate::preferences::{Density, DENSITY_KEY};
+
+fn root_element() -> Option<web_sys::Element> {
+    web_sys::window()?.document()?.document_element()
+}
+
+/// Custom properties read by index.scss, each falling back to the built-in colour
+const COLOR_OVERRIDES: [(&str, Option<&str>); 2] = [
+    ("--pandora-primary", PRIMARY_COLOR),
+    ("--pandora-link", LINK_COLOR),
+];
+
+/// Sets the configured colours on the root element. Values the browser doesn't
+/// accept as colours are skipped, since an invalid custom property would blank
+/// the element instead of falling back
+pub fn apply_color_overrides() {
+    let Some(root) = root_element().and_then(|root| root.dyn_into::<web_sys::HtmlElement>().ok()) else {
+        return;
+    };
+
+    for (property, color) in COLOR_OVERRIDES.iter().copied() {
+        let Some(color) = color.map(str::trim) else {
+            continue;
+        };
+        if !web_sys::css::supports_with_value("color", color).unwrap_or(false) {
+            log::warn!("Ignoring {}: {:?} is not a CSS colour", property, color);
+            continue;
+        }
+        if let Err(error) = root.style().set_property(property, color) {
+            log::warn!("Failed to set {}: {:?}", property, error);
+        }
+    }
+}
+
+/// Sets `data-density` on the root element, which index.scss uses to tighten spacing
+pub fn apply_density(density: Density) {
+    if let Some(root) = root_element() {
+        let _ = root.set_attribute("data-density", density.id());
+    }
+}
+
+/// Applies the stored density before the first render, so compact pages don't
+/// jump from comfortable spacing on load
+pub fn apply_stored_density() {
+    apply_density(LocalStorage::get(DENSITY_KEY).unwrap_or_default());
+}
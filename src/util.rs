@@ -0,0 +1,120 @@
+//! Small formatting helpers shared across pages
+
+use chrono::{DateTime, Utc};
+use js_sys::{Array, Intl, Object, Reflect};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+
+use crate::services::cache::now_millis;
+
+/// Formatting follows the browser's locale when `Intl` exists, and falls back
+/// to plain digits and ISO dates otherwise
+fn intl_available() -> bool {
+    Reflect::has(&js_sys::global(), &JsValue::from_str("Intl")).unwrap_or(false)
+}
+
+/// `12345` as "12,345", "12.345" or "12 345" depending on the locale
+pub fn format_number(value: u64) -> String {
+    if intl_available() {
+        let format = Intl::NumberFormat::new(&Array::new(), &Object::new());
+        let formatted = format.format().call1(&JsValue::NULL, &JsValue::from_f64(value as f64));
+        if let Some(formatted) = formatted.ok().and_then(|formatted| formatted.as_string()) {
+            return formatted;
+        }
+    }
+    value.to_string()
+}
+
+/// Sizes in binary units: "512 B", "1.0 KB", "82.4 MB", "1.2 GB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // Move up a unit before rounding could show 1048575 bytes as "1024.0 KB"
+    while value >= 1023.95 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// A long, locale-formatted date such as "March 4, 2024" or "4. März 2024"
+pub fn format_date(date: DateTime<Utc>) -> String {
+    if intl_available() {
+        let options = Object::new();
+        let _ = Reflect::set(&options, &JsValue::from_str("dateStyle"), &JsValue::from_str("long"));
+        let format = Intl::DateTimeFormat::new(&Array::new(), &options);
+        let js_date = js_sys::Date::new(&JsValue::from_f64(date.timestamp_millis() as f64));
+        let formatted = format.format().call1(&JsValue::NULL, &js_date);
+        if let Some(formatted) = formatted.ok().and_then(|formatted| formatted.as_string()) {
+            return formatted;
+        }
+    }
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// "just now", "5 minutes ago", "3 days ago" and so on, relative to the browser's clock
+pub fn format_relative(date: DateTime<Utc>) -> String {
+    let elapsed_seconds = ((now_millis() as i64 - date.timestamp_millis()) / 1000).max(0);
+
+    let units = [
+        (60 * 60 * 24 * 365, "year"),
+        (60 * 60 * 24 * 30, "month"),
+        (60 * 60 * 24 * 7, "week"),
+        (60 * 60 * 24, "day"),
+        (60 * 60, "hour"),
+        (60, "minute"),
+    ];
+    for (seconds, unit) in units.iter().copied() {
+        let count = elapsed_seconds / seconds;
+        if count >= 1 {
+            return format!("{} {}{} ago", count, unit, if count == 1 { "" } else { "s" });
+        }
+    }
+    "just now".into()
+}
+
+/// Runs `task` once the browser is idle, or after a short delay where
+/// `requestIdleCallback` isn't supported (Safari)
+pub fn run_when_idle(task: impl FnOnce() + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let supported = Reflect::has(&window, &JsValue::from_str("requestIdleCallback")).unwrap_or(false);
+    if supported {
+        let callback = Closure::once_into_js(task);
+        if window.request_idle_callback(callback.unchecked_ref()).is_err() {
+            log::warn!("requestIdleCallback failed, skipping idle task");
+        }
+    } else {
+        gloo::timers::callback::Timeout::new(2000, task).forget();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_boundaries() {
+        let cases = [
+            (0, "0 B"),
+            (512, "512 B"),
+            (1023, "1023 B"),
+            (1024, "1.0 KB"),
+            (1536, "1.5 KB"),
+            (1024 * 1024 - 1, "1.0 MB"),
+            (1024 * 1024, "1.0 MB"),
+            (86_402_662, "82.4 MB"),
+            (1024 * 1024 * 1024, "1.0 GB"),
+            (1_288_490_189, "1.2 GB"),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(format_bytes(bytes), expected, "{} bytes", bytes);
+        }
+    }
+}
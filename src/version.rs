@@ -0,0 +1,70 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// A semantic version as used by release tags, e.g. `v1.4.2` or `1.5.0-beta.1`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Vec<String>,
+}
+
+impl FromStr for Version {
+    type Err = ();
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let tag = tag.trim();
+        let tag = tag.strip_prefix(['v', 'V']).unwrap_or(tag);
+        let tag = tag.split('+').next().unwrap_or(tag);
+
+        let (core, pre) = match tag.split_once('-') {
+            Some((core, pre)) => (core, pre.split('.').map(String::from).collect()),
+            None => (tag, Vec::new()),
+        };
+
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().map_err(|_| ()));
+        let major = parts.next().ok_or(())??;
+        let minor = parts.next().transpose()?.unwrap_or(0);
+        let patch = parts.next().transpose()?.unwrap_or(0);
+        if parts.next().is_some() {
+            return Err(());
+        }
+
+        Ok(Version { major, minor, patch, pre })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => compare_pre(&self.pre, &other.pre),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn compare_pre(a: &[String], b: &[String]) -> Ordering {
+    for (a, b) in a.iter().zip(b) {
+        let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+            (Ok(a), Ok(b)) => a.cmp(&b),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => a.cmp(b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a.len().cmp(&b.len())
+}